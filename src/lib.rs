@@ -1,6 +1,37 @@
 //! Extra functionality for Rust arrays.
+#![no_std]
 #![cfg_attr(feature = "nightly", feature(generic_const_exprs, array_try_from_fn))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::mem::MaybeUninit;
+use core::ops::{Add, Div, Mul, RangeBounds, Sub};
+
+/// Re-exports all the extension traits.
+///
+/// # Examples
+/// ```
+/// use array_ext::prelude::*;
+///
+/// let flags = [Some(true), Some(false), Some(true)];
+/// assert_eq!(flags.transpose_options().map(|f| f.count_true()), Some(2));
+/// ```
+pub mod prelude {
+    #[cfg(feature = "std")]
+    pub use crate::FloatArray;
+    pub use crate::{
+        Array, Array3, ArrayN, BoolArray, ConstLen, IntoTuple, NestedArray, OptionArray, ResultArray, TripleArray,
+    };
+}
+
 /// Generic array type.
 ///
 /// This trait allows passing arrays by value in a generic way without turning them into slices,
@@ -37,18 +68,342 @@ pub trait Array<T> {
     /// Returns a mutable pointer to the last element of the array, or `None` if it is empty.
     fn last_mut(&mut self) -> Option<&mut T>;
 
+    /// Returns the first element of the array and a slice of the rest, or `None` if it is empty.
+    fn head_tail(&self) -> Option<(&T, &[T])>;
+
     /// Returns the element of an array at the given index, or `None` if the index is out of bounds.
     fn get(&self, index: usize) -> Option<&T>;
 
     /// Returns a mutable reference to the element at the given index, or `None` if the index is out of bounds.
     fn get_mut(&mut self, index: usize) -> Option<&mut T>;
 
+    /// Returns a sub-slice of the array for the given range, or `None` if the range is out of bounds.
+    fn get_range(&self, range: impl RangeBounds<usize>) -> Option<&[T]>
+    where
+        Self: Sized;
+
+    /// Returns a mutable sub-slice of the array for the given range, or `None` if the range is out of bounds.
+    fn get_range_mut(&mut self, range: impl RangeBounds<usize>) -> Option<&mut [T]>
+    where
+        Self: Sized;
+
+    /// Returns the element of an array at the given index, or `default` if the index is out of bounds.
+    fn get_or<'a>(&'a self, index: usize, default: &'a T) -> &'a T;
+
+    /// Returns the element of an array at the given index, or the result of `f` if the index is out of bounds.
+    fn get_or_else<'a, F>(&'a self, index: usize, f: F) -> &'a T
+    where
+        F: FnOnce() -> &'a T,
+        Self: Sized;
+
+    /// Creates a new array by cloning the elements at the given indices, in order.
+    ///
+    /// Indices can be repeated, and the resulting array can have a different size.
+    ///
+    /// # Panics
+    /// Panics if any of the indices is out of bounds.
+    fn swizzle<const M: usize>(&self, indices: [usize; M]) -> [T; M]
+    where
+        T: Clone,
+        Self: Sized;
+
+    /// Creates a new array by cloning the elements at the given indices, or `None` if any of the indices
+    /// is out of bounds.
+    fn try_swizzle<const M: usize>(&self, indices: [usize; M]) -> Option<[T; M]>
+    where
+        T: Clone,
+        Self: Sized;
+
     /// Extracts a slice containing the entire array.
     fn as_slice(&self) -> &[T];
 
     /// Extracts a mutable slice of the entire array.
     fn as_mut_slice(&mut self) -> &mut [T];
 
+    /// Swaps two elements in the array.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` are out of bounds.
+    fn swap(&mut self, i: usize, j: usize);
+
+    /// Fills the array with clones of the given value.
+    fn fill(&mut self, value: T)
+    where
+        T: Clone;
+
+    /// Fills the array with values returned by calling a closure repeatedly.
+    fn fill_with<F>(&mut self, f: F)
+    where
+        F: FnMut() -> T,
+        Self: Sized;
+
+    /// Sorts the array in place.
+    ///
+    /// This sort is stable, see [`slice::sort`].
+    #[cfg(feature = "alloc")]
+    fn sort(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the array in place, without preserving the order of equal elements.
+    ///
+    /// See [`slice::sort_unstable`].
+    fn sort_unstable(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the array in place with a comparator function.
+    ///
+    /// This sort is stable, see [`slice::sort_by`].
+    #[cfg(feature = "alloc")]
+    fn sort_by<F>(&mut self, f: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        Self: Sized;
+
+    /// Sorts the array in place with a key extraction function.
+    ///
+    /// This sort is stable, see [`slice::sort_by_key`].
+    #[cfg(feature = "alloc")]
+    fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+        Self: Sized;
+
+    /// Binary searches this sorted array for the given element.
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with the index where it could be
+    /// inserted while keeping the array sorted. See [`slice::binary_search`].
+    fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord;
+
+    /// Binary searches this sorted array with a comparator function.
+    ///
+    /// The comparator should return the ordering of the element relative to the target. See
+    /// [`slice::binary_search_by`].
+    fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+        Self: Sized;
+
+    /// Returns the index of the first element that satisfies the predicate, or `None` if there is none.
+    fn position<F>(&self, f: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+        Self: Sized;
+
+    /// Returns a reference to the first element that satisfies the predicate, or `None` if there is none.
+    fn find<F>(&self, f: F) -> Option<&T>
+    where
+        F: FnMut(&T) -> bool,
+        Self: Sized;
+
+    /// Applies the function to the elements in order and returns the first non-`None` result.
+    fn find_map<U, F>(&self, f: F) -> Option<U>
+    where
+        F: FnMut(&T) -> Option<U>,
+        Self: Sized;
+
+    /// Tests if every element of the array satisfies the predicate.
+    ///
+    /// Returns `true` for an empty array.
+    fn all<F>(&self, f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+        Self: Sized;
+
+    /// Tests if any element of the array satisfies the predicate.
+    ///
+    /// Returns `false` for an empty array.
+    fn any<F>(&self, f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+        Self: Sized;
+
+    /// Returns `true` if the array contains an element equal to the given value.
+    fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq;
+
+    /// Returns `true` if the array reads the same forwards and backwards.
+    ///
+    /// Empty and single element arrays are palindromes.
+    fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq;
+
+    /// Returns the number of elements that satisfy the predicate.
+    fn count<F>(&self, f: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+        Self: Sized;
+
+    /// Returns the indices of all the elements that satisfy the predicate, in ascending order.
+    #[cfg(feature = "alloc")]
+    fn positions<F>(&self, f: F) -> Vec<usize>
+    where
+        F: FnMut(&T) -> bool,
+        Self: Sized;
+
+    /// Returns the number of elements at the start of the array that satisfy the predicate.
+    fn count_leading<F>(&self, f: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+        Self: Sized;
+
+    /// Returns the number of elements at the end of the array that satisfy the predicate.
+    fn count_trailing<F>(&self, f: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+        Self: Sized;
+
+    /// Returns the indices of the elements that are strictly greater than both of their neighbors.
+    ///
+    /// The first and last elements only have one neighbor, so they are excluded. Use
+    /// [`local_maxima_with`](Array::local_maxima_with) to include them.
+    #[cfg(feature = "alloc")]
+    fn local_maxima(&self) -> Vec<usize>
+    where
+        T: PartialOrd;
+
+    /// Returns the indices of the elements that are strictly less than both of their neighbors.
+    ///
+    /// The first and last elements only have one neighbor, so they are excluded. Use
+    /// [`local_minima_with`](Array::local_minima_with) to include them.
+    #[cfg(feature = "alloc")]
+    fn local_minima(&self) -> Vec<usize>
+    where
+        T: PartialOrd;
+
+    /// Returns the indices of the elements that are strictly greater than all of their neighbors.
+    ///
+    /// If `include_edges` is `true`, the first and last elements are included when they are strictly
+    /// greater than their single neighbor. An array of length 1 has no maxima.
+    #[cfg(feature = "alloc")]
+    fn local_maxima_with(&self, include_edges: bool) -> Vec<usize>
+    where
+        T: PartialOrd;
+
+    /// Returns the indices of the elements that are strictly less than all of their neighbors.
+    ///
+    /// If `include_edges` is `true`, the first and last elements are included when they are strictly
+    /// less than their single neighbor. An array of length 1 has no minima.
+    #[cfg(feature = "alloc")]
+    fn local_minima_with(&self, include_edges: bool) -> Vec<usize>
+    where
+        T: PartialOrd;
+
+    /// Returns the starting indices of every occurrence of `pat` in the array, in ascending order.
+    ///
+    /// Overlapping occurrences are all reported. An empty pattern matches at every index from `0` to
+    /// the array length.
+    #[cfg(feature = "alloc")]
+    fn find_all_subarrays<const M: usize>(&self, pat: &[T; M]) -> Vec<usize>
+    where
+        T: PartialEq,
+        Self: Sized;
+
+    /// Returns the minimum element of the array, or `None` if it is empty.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    fn min_(&self) -> Option<&T>
+    where
+        T: Ord;
+
+    /// Returns the maximum element of the array, or `None` if it is empty.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    fn max_(&self) -> Option<&T>
+    where
+        T: Ord;
+
+    /// Returns the elements that give the minimum and maximum values from the specified function, or `None`
+    /// if the array is empty.
+    ///
+    /// If several elements are equally minimum, the first one is returned. If several elements are equally
+    /// maximum, the last one is returned.
+    fn minmax_by_key<K, F>(&self, f: F) -> Option<(&T, &T)>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+        Self: Sized;
+
+    /// Returns the element that gives the minimum value from the specified function, or `None` if the
+    /// array is empty.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    fn min_by_key<K, F>(&self, f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+        Self: Sized;
+
+    /// Returns the element that gives the maximum value from the specified function, or `None` if the
+    /// array is empty.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    fn max_by_key<K, F>(&self, f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+        Self: Sized;
+
+    /// Returns an iterator that yields the elements of the array `repeats` times in order.
+    fn cycle_n<'a>(&'a self, repeats: usize) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+        Self: Sized;
+
+    /// Rotates the array to the left so the first element that satisfies the predicate becomes the first
+    /// element.
+    ///
+    /// Returns `false` and leaves the array unchanged if no element matches.
+    fn rotate_to<F>(&mut self, f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+        Self: Sized;
+
+    /// Splits the array into a reference to the first `M` elements and a slice of the rest, or `None` if
+    /// `M` is greater than the array length.
+    fn try_split_array_ref<const M: usize>(&self) -> Option<(&[T; M], &[T])>
+    where
+        Self: Sized;
+
+    /// Splits the array into a mutable reference to the first `M` elements and a mutable slice of the rest,
+    /// or `None` if `M` is greater than the array length.
+    fn try_split_array_mut<const M: usize>(&mut self) -> Option<(&mut [T; M], &mut [T])>
+    where
+        Self: Sized;
+
+    /// Splits the array into a slice of `C`-element arrays and a remainder slice with less than `C`
+    /// elements.
+    ///
+    /// # Panics
+    /// Panics if `C` is 0.
+    fn as_chunks<const C: usize>(&self) -> (&[[T; C]], &[T])
+    where
+        Self: Sized;
+
+    /// Splits the array into a mutable slice of `C`-element arrays and a mutable remainder slice with less
+    /// than `C` elements.
+    ///
+    /// # Panics
+    /// Panics if `C` is 0.
+    fn as_chunks_mut<const C: usize>(&mut self) -> (&mut [[T; C]], &mut [T])
+    where
+        Self: Sized;
+
+    /// Calls a `FnMut(&mut T, &mut T)` closure on each pair of adjacent elements, from left to right.
+    ///
+    /// The pairs overlap: element `i + 1` is the second argument of one call and the first argument of
+    /// the next, so it sees any modification made by the previous call.
+    fn adjacent_apply_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T, &mut T),
+        Self: Sized;
+
     /// Takes a `FnMut(T) -> T` closure and creates a new array by calling that closure on each element.
     fn map_<F>(self, f: F) -> Self
     where
@@ -67,6 +422,73 @@ pub trait Array<T> {
         F: FnMut(A, T) -> A,
         Self: Sized;
 
+    /// Applies a function over the entire array, producing an array with `init` followed by each of the
+    /// intermediate accumulator values.
+    ///
+    /// The size of the resulting array `M` must be the length of this array plus one.
+    ///
+    /// # Panics
+    /// Panics if `M` isn't equal to the length of the array plus one.
+    fn scanl<A, F, const M: usize>(self, init: A, f: F) -> [A; M]
+    where
+        A: Clone,
+        F: FnMut(&A, T) -> A,
+        Self: Sized;
+
+    /// Reduces the elements to a single one by repeatedly applying a function, using the first element as
+    /// the initial value. Returns `None` if the array is empty.
+    fn reduce<F>(self, f: F) -> Option<T>
+    where
+        F: FnMut(T, T) -> T,
+        Self: Sized;
+
+    /// Applies a fallible function over the entire array, stopping at the first error.
+    ///
+    /// This works like `Iterator::try_fold`: the remaining elements are dropped without being processed.
+    fn try_foldl<A, E, F>(self, acc: A, f: F) -> Result<A, E>
+    where
+        F: FnMut(A, T) -> Result<A, E>,
+        Self: Sized;
+
+    /// Applies a function over references to the elements with the index of each element, producing a
+    /// single final value.
+    fn weighted_fold<A, F>(&self, init: A, f: F) -> A
+    where
+        F: FnMut(A, usize, &T) -> A,
+        Self: Sized;
+
+    /// Applies a function over references to the elements in both directions in a single pass, producing
+    /// the results of the left and right folds.
+    fn fold_both<A, F>(&self, init: A, f: F) -> (A, A)
+    where
+        A: Clone,
+        F: FnMut(A, &T) -> A,
+        Self: Sized;
+
+    /// Applies a fallible function over the entire array with the index of each element, stopping at the
+    /// first error.
+    ///
+    /// The remaining elements are dropped without being processed.
+    fn try_foldl_indexed<A, E, F>(self, acc: A, f: F) -> Result<A, E>
+    where
+        F: FnMut(A, usize, T) -> Result<A, E>,
+        Self: Sized;
+
+    /// Splits the array into chunks of `C` elements measured from the end, and folds each chunk
+    /// separately starting from `init`.
+    ///
+    /// The results are returned in array order, so if `C` doesn't divide the length, the first result
+    /// comes from the shorter chunk at the front.
+    ///
+    /// # Panics
+    /// Panics if `C` is 0.
+    #[cfg(feature = "alloc")]
+    fn rchunk_fold<const C: usize, A, F>(self, init: A, f: F) -> Vec<A>
+    where
+        A: Clone,
+        F: FnMut(A, T) -> A,
+        Self: Sized;
+
     /// Resizes the array, filling new spaces at the end with the specified element.
     fn resize<const S: usize>(self, elem: T) -> [T; S]
     where
@@ -74,22 +496,80 @@ pub trait Array<T> {
         Self: Sized;
 
     /// Resizes the array, filling new spaces at the end with the values generated from a function.
+    ///
+    /// The function receives the index of the new element in the resulting array.
     fn resize_with<F, const S: usize>(self, f: F) -> [T; S]
     where
         F: FnMut(usize) -> T,
         Self: Sized;
 
-    #[deprecated(since = "0.4.0", note = "use std::array::from_fn instead")]
+    /// Resizes the array, filling new spaces at the end with the values generated from a function.
+    ///
+    /// Unlike `resize_with`, the function receives the offset of the new element past the end of the
+    /// original array, so the first new element gets 0.
+    fn resize_with_offset<F, const S: usize>(self, f: F) -> [T; S]
+    where
+        F: FnMut(usize) -> T,
+        Self: Sized;
+
+    #[deprecated(since = "0.4.0", note = "use core::array::from_fn instead")]
     /// Creates a new array using the provided closure.
     fn from_fn<F>(f: F) -> Self
     where
         F: FnMut(usize) -> T,
         Self: Sized;
 
+    /// Creates an array with every element set to `T::default()`.
+    fn from_default() -> Self
+    where
+        T: Default,
+        Self: Sized;
+
+    /// Creates an array with clones of the given element.
+    ///
+    /// The element is moved into the last position, so only `N - 1` clones are made.
+    fn repeat(elem: T) -> Self
+    where
+        T: Clone,
+        Self: Sized;
+
     /// Creates an array by extracting elements from the provided iterator.
     fn from_iter(iter: impl Iterator<Item = T>) -> Option<Self>
     where
         Self: Sized;
+
+    /// Creates an array by extracting elements from the provided iterator.
+    ///
+    /// If the iterator runs out early, returns the number of elements it produced. The elements already
+    /// extracted are dropped.
+    fn try_from_iter(iter: impl Iterator<Item = T>) -> Result<Self, usize>
+    where
+        Self: Sized;
+
+    /// Creates an array by cloning the elements of a slice.
+    ///
+    /// Returns `None` if the slice length doesn't match the array length.
+    fn from_slice(slice: &[T]) -> Option<Self>
+    where
+        T: Clone,
+        Self: Sized;
+
+    /// Creates a new array using the provided fallible closure, stopping at the first error.
+    ///
+    /// The elements created before the error are dropped.
+    fn try_from_fn<E, F>(f: F) -> Result<Self, E>
+    where
+        F: FnMut(usize) -> Result<T, E>,
+        Self: Sized;
+
+    /// Creates an array by extracting elements from the provided iterator, filling the remaining
+    /// spaces with the values generated from a function if it runs out.
+    ///
+    /// Items past the end of the array are dropped along with the iterator.
+    fn from_iter_padded<F>(iter: impl Iterator<Item = T>, pad: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+        Self: Sized;
 }
 
 impl<T, const N: usize> Array<T> for [T; N] {
@@ -139,6 +619,11 @@ impl<T, const N: usize> Array<T> for [T; N] {
         }
     }
 
+    #[inline]
+    fn head_tail(&self) -> Option<(&T, &[T])> {
+        self.as_slice().split_first()
+    }
+
     #[inline]
     fn get(&self, index: usize) -> Option<&T> {
         if index < N {
@@ -158,219 +643,2104 @@ impl<T, const N: usize> Array<T> for [T; N] {
     }
 
     #[inline]
-    fn as_slice(&self) -> &[T] {
-        self
+    fn get_range(&self, range: impl RangeBounds<usize>) -> Option<&[T]> {
+        self.as_slice()
+            .get((range.start_bound().cloned(), range.end_bound().cloned()))
     }
 
     #[inline]
-    fn as_mut_slice(&mut self) -> &mut [T] {
-        self
+    fn get_range_mut(&mut self, range: impl RangeBounds<usize>) -> Option<&mut [T]> {
+        self.as_mut_slice()
+            .get_mut((range.start_bound().cloned(), range.end_bound().cloned()))
     }
 
     #[inline]
-    fn map_<F>(self, f: F) -> Self
+    fn get_or<'a>(&'a self, index: usize, default: &'a T) -> &'a T {
+        self.get_or_else(index, || default)
+    }
+
+    #[inline]
+    fn get_or_else<'a, F>(&'a self, index: usize, f: F) -> &'a T
     where
-        F: FnMut(T) -> T,
+        F: FnOnce() -> &'a T,
     {
-        self.map(f)
+        Array::get(self, index).unwrap_or_else(f)
     }
 
     #[inline]
-    fn foldl<A, F>(self, mut acc: A, mut f: F) -> A
+    fn swizzle<const M: usize>(&self, indices: [usize; M]) -> [T; M]
     where
-        F: FnMut(A, T) -> A,
+        T: Clone,
     {
-        for val in self {
-            acc = f(acc, val);
-        }
-        acc
+        indices.map(|i| match Array::get(self, i) {
+            Some(val) => val.clone(),
+            None => panic!("swizzle index {i} is out of bounds for array of length {N}"),
+        })
     }
 
     #[inline]
-    fn foldr<A, F>(self, mut acc: A, mut f: F) -> A
+    fn try_swizzle<const M: usize>(&self, indices: [usize; M]) -> Option<[T; M]>
     where
-        F: FnMut(A, T) -> A,
+        T: Clone,
     {
-        for val in self.into_iter().rev() {
-            acc = f(acc, val);
-        }
-        acc
+        Array::from_iter(indices.into_iter().map_while(|i| Array::get(self, i).cloned()))
     }
 
     #[inline]
-    fn resize<const S: usize>(self, elem: T) -> [T; S]
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    #[inline]
+    fn swap(&mut self, i: usize, j: usize) {
+        self.as_mut_slice().swap(i, j)
+    }
+
+    #[inline]
+    fn fill(&mut self, value: T)
     where
         T: Clone,
     {
-        self.resize_with(|_| elem.clone())
+        self.as_mut_slice().fill(value)
     }
 
     #[inline]
-    fn resize_with<F, const S: usize>(self, mut f: F) -> [T; S]
+    fn fill_with<F>(&mut self, f: F)
     where
-        F: FnMut(usize) -> T,
+        F: FnMut() -> T,
     {
-        let mut a = self.into_iter();
-        std::array::from_fn(|i| if i < N { a.next().unwrap() } else { f(i) })
+        self.as_mut_slice().fill_with(f)
     }
 
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn from_fn<F>(f: F) -> Self
+    fn sort(&mut self)
     where
-        F: FnMut(usize) -> T,
+        T: Ord,
     {
-        std::array::from_fn(f)
+        self.as_mut_slice().sort()
     }
 
     #[inline]
-    fn from_iter(mut iter: impl Iterator<Item = T>) -> Option<Self> {
-        #[cfg(feature = "nightly")]
-        {
-            std::array::try_from_fn(|_| iter.next())
-        }
-        #[cfg(not(feature = "nightly"))]
-        {
-            let mut arr = std::array::from_fn(|_| None);
-            for item in &mut arr {
-                *item = Some(iter.next()?);
-            }
-            Some(arr.map(Option::unwrap))
-        }
+    fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().sort_unstable()
     }
-}
 
-/// Array with size information on the type.
-pub trait ArrayN<T, const N: usize>: Array<T> {
-    /// Merges elements with another array by calling a `FnMut(T, U) -> Output` closure for each pair.
-    fn zip_with<U, Output, F>(self, other: [U; N], f: F) -> [Output; N]
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn sort_by<F>(&mut self, f: F)
     where
-        F: FnMut(T, U) -> Output,
-        Self: Sized;
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.as_mut_slice().sort_by(f)
+    }
 
-    /// Merges elements with another two arrays by calling a `FnMut(T, U, V) -> Output` closure for each tuple.
-    fn zip3_with<U, V, Output, F>(self, other1: [U; N], other2: [V; N], f: F) -> [Output; N]
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn sort_by_key<K, F>(&mut self, f: F)
     where
-        F: FnMut(T, U, V) -> Output,
-        Self: Sized;
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.as_mut_slice().sort_by_key(f)
+    }
 
-    /// Merges elements with another three arrays by calling a `FnMut(T, U, V, W) -> Output` closure for each tuple.
-    fn zip4_with<U, V, W, Output, F>(self, other1: [U; N], other2: [V; N], other3: [W; N], f: F) -> [Output; N]
+    #[inline]
+    fn binary_search(&self, x: &T) -> Result<usize, usize>
     where
-        F: FnMut(T, U, V, W) -> Output,
-        Self: Sized;
+        T: Ord,
+    {
+        self.as_slice().binary_search(x)
+    }
 
-    /// Merges elements with another four arrays by calling a `FnMut(T, U, V, W, X) -> Output` closure for each tuple.
-    fn zip5_with<U, V, W, X, Output, F>(
-        self, other1: [U; N], other2: [V; N], other3: [W; N], other4: [X; N], f: F,
-    ) -> [Output; N]
+    #[inline]
+    fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
     where
-        F: FnMut(T, U, V, W, X) -> Output,
-        Self: Sized;
+        F: FnMut(&T) -> Ordering,
+    {
+        self.as_slice().binary_search_by(f)
+    }
 
-    /// Converts this object into it's concrete array type.
-    fn downcast(self) -> [T; N];
+    #[inline]
+    fn position<F>(&self, f: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().position(f)
+    }
 
-    /// Gets a reference to this object's concrete array type.
-    fn downcast_ref(&self) -> &[T; N];
+    #[inline]
+    fn find<F>(&self, mut f: F) -> Option<&T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().find(|x| f(x))
+    }
 
-    /// Gets a mutable reference to this object's concrete array type.
-    fn downcast_mut(&mut self) -> &mut [T; N];
+    #[inline]
+    fn find_map<U, F>(&self, f: F) -> Option<U>
+    where
+        F: FnMut(&T) -> Option<U>,
+    {
+        self.iter().find_map(f)
+    }
 
-    /// Concatenates two arrays together.
-    #[cfg(feature = "nightly")]
-    fn concat<const M: usize>(self, other: [T; M]) -> [T; N + M]
+    #[inline]
+    fn all<F>(&self, f: F) -> bool
     where
-        Self: Sized;
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().all(f)
+    }
 
-    /// Splits an array into two sub-arrays.
-    #[cfg(feature = "nightly")]
-    fn split<const P: usize>(self) -> ([T; P], [T; N - P])
+    #[inline]
+    fn any<F>(&self, f: F) -> bool
     where
-        Self: Sized;
-}
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().any(f)
+    }
 
-impl<T, const N: usize> ArrayN<T, N> for [T; N] {
     #[inline]
-    fn zip_with<U, Output, F>(self, other: [U; N], mut f: F) -> [Output; N]
+    fn contains(&self, x: &T) -> bool
     where
-        F: FnMut(T, U) -> Output,
+        T: PartialEq,
     {
-        let mut b = other.into_iter();
-        self.map(|a| f(a, b.next().unwrap()))
+        self.iter().any(|e| e == x)
     }
 
     #[inline]
-    fn zip3_with<U, V, Output, F>(self, other1: [U; N], other2: [V; N], mut f: F) -> [Output; N]
+    fn is_palindrome(&self) -> bool
     where
-        F: FnMut(T, U, V) -> Output,
+        T: PartialEq,
     {
-        let mut it1 = other1.into_iter();
-        let mut it2 = other2.into_iter();
-        self.map(|x0| f(x0, it1.next().unwrap(), it2.next().unwrap()))
+        self.iter().take(N / 2).eq(self.iter().rev().take(N / 2))
     }
 
     #[inline]
-    fn zip4_with<U, V, W, Output, F>(self, other1: [U; N], other2: [V; N], other3: [W; N], mut f: F) -> [Output; N]
+    fn count<F>(&self, mut f: F) -> usize
     where
-        F: FnMut(T, U, V, W) -> Output,
+        F: FnMut(&T) -> bool,
     {
-        let mut it1 = other1.into_iter();
-        let mut it2 = other2.into_iter();
-        let mut it3 = other3.into_iter();
-        self.map(|x0| f(x0, it1.next().unwrap(), it2.next().unwrap(), it3.next().unwrap()))
+        self.iter().filter(|x| f(x)).count()
     }
 
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn zip5_with<U, V, W, X, Output, F>(
-        self, other1: [U; N], other2: [V; N], other3: [W; N], other4: [X; N], mut f: F,
-    ) -> [Output; N]
+    fn positions<F>(&self, mut f: F) -> Vec<usize>
     where
-        F: FnMut(T, U, V, W, X) -> Output,
+        F: FnMut(&T) -> bool,
     {
-        let mut it1 = other1.into_iter();
-        let mut it2 = other2.into_iter();
-        let mut it3 = other3.into_iter();
-        let mut it4 = other4.into_iter();
-        self.map(|x0| {
-            f(
-                x0,
-                it1.next().unwrap(),
-                it2.next().unwrap(),
-                it3.next().unwrap(),
-                it4.next().unwrap(),
-            )
-        })
+        (0..N).filter(|&i| f(&self[i])).collect()
     }
 
     #[inline]
-    fn downcast(self) -> [T; N] {
-        self
+    fn count_leading<F>(&self, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().take_while(|x| f(x)).count()
     }
 
     #[inline]
-    fn downcast_ref(&self) -> &[T; N] {
-        self
+    fn count_trailing<F>(&self, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().rev().take_while(|x| f(x)).count()
     }
 
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn downcast_mut(&mut self) -> &mut [T; N] {
-        self
+    fn local_maxima(&self) -> Vec<usize>
+    where
+        T: PartialOrd,
+    {
+        self.local_maxima_with(false)
     }
 
-    #[cfg(feature = "nightly")]
-    fn concat<const M: usize>(self, other: [T; M]) -> [T; N + M] {
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn local_minima(&self) -> Vec<usize>
+    where
+        T: PartialOrd,
+    {
+        self.local_minima_with(false)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn local_maxima_with(&self, include_edges: bool) -> Vec<usize>
+    where
+        T: PartialOrd,
+    {
+        (0..N)
+            .filter(|&i| match (i.checked_sub(1).map(|j| &self[j]), self.get(i + 1)) {
+                (Some(l), Some(r)) => self[i] > *l && self[i] > *r,
+                (Some(n), None) | (None, Some(n)) => include_edges && self[i] > *n,
+                (None, None) => false,
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn local_minima_with(&self, include_edges: bool) -> Vec<usize>
+    where
+        T: PartialOrd,
+    {
+        (0..N)
+            .filter(|&i| match (i.checked_sub(1).map(|j| &self[j]), self.get(i + 1)) {
+                (Some(l), Some(r)) => self[i] < *l && self[i] < *r,
+                (Some(n), None) | (None, Some(n)) => include_edges && self[i] < *n,
+                (None, None) => false,
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn try_split_array_ref<const M: usize>(&self) -> Option<(&[T; M], &[T])> {
+        self.split_first_chunk()
+    }
+
+    #[inline]
+    fn try_split_array_mut<const M: usize>(&mut self) -> Option<(&mut [T; M], &mut [T])> {
+        self.split_first_chunk_mut()
+    }
+
+    #[inline]
+    fn as_chunks<const C: usize>(&self) -> (&[[T; C]], &[T]) {
+        self.as_slice().as_chunks()
+    }
+
+    #[inline]
+    fn as_chunks_mut<const C: usize>(&mut self) -> (&mut [[T; C]], &mut [T]) {
+        self.as_mut_slice().as_chunks_mut()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn find_all_subarrays<const M: usize>(&self, pat: &[T; M]) -> Vec<usize>
+    where
+        T: PartialEq,
+    {
+        (0..(N + 1).saturating_sub(M))
+            .filter(|&i| self[i..i + M] == pat[..])
+            .collect()
+    }
+
+    #[inline]
+    fn min_(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().min()
+    }
+
+    #[inline]
+    fn max_(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().max()
+    }
+
+    fn minmax_by_key<K, F>(&self, mut f: F) -> Option<(&T, &T)>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let (a, b, rest) = match self.as_slice() {
+            [] => return None,
+            [a] => return Some((a, a)),
+            [a, b, rest @ ..] => (a, b, rest),
+        };
+        let (ka, kb) = (f(a), f(b));
+        let (mut min, mut max) = if kb < ka {
+            ((b, kb), (a, ka))
+        } else {
+            ((a, ka), (b, kb))
+        };
+        for x in rest {
+            let k = f(x);
+            if k < min.1 {
+                min = (x, k);
+            } else if k >= max.1 {
+                max = (x, k);
+            }
+        }
+        Some((min.0, max.0))
+    }
+
+    #[inline]
+    fn min_by_key<K, F>(&self, mut f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.iter().min_by_key(|x| f(x))
+    }
+
+    #[inline]
+    fn max_by_key<K, F>(&self, mut f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.iter().max_by_key(|x| f(x))
+    }
+
+    #[inline]
+    fn cycle_n<'a>(&'a self, repeats: usize) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        let repeats = if N == 0 { 0 } else { repeats };
+        (0..repeats).flat_map(move |_| self.iter())
+    }
+
+    #[inline]
+    fn rotate_to<F>(&mut self, f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        match self.iter().position(f) {
+            Some(mid) => {
+                self.as_mut_slice().rotate_left(mid);
+                true
+            }
+            None => false,
+        }
+    }
+
+    #[inline]
+    fn adjacent_apply_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T, &mut T),
+    {
+        for i in 1..N {
+            let (a, b) = self.split_at_mut(i);
+            f(&mut a[i - 1], &mut b[0]);
+        }
+    }
+
+    #[inline]
+    fn map_<F>(self, f: F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        self.map(f)
+    }
+
+    #[inline]
+    fn foldl<A, F>(self, mut acc: A, mut f: F) -> A
+    where
+        F: FnMut(A, T) -> A,
+    {
+        for val in self {
+            acc = f(acc, val);
+        }
+        acc
+    }
+
+    #[inline]
+    fn foldr<A, F>(self, mut acc: A, mut f: F) -> A
+    where
+        F: FnMut(A, T) -> A,
+    {
+        for val in self.into_iter().rev() {
+            acc = f(acc, val);
+        }
+        acc
+    }
+
+    #[inline]
+    fn scanl<A, F, const M: usize>(self, init: A, mut f: F) -> [A; M]
+    where
+        A: Clone,
+        F: FnMut(&A, T) -> A,
+    {
+        assert_eq!(M, N + 1, "scanl output size must be the input size plus one");
         let mut a = self.into_iter();
-        let mut b = other.into_iter();
-        std::array::from_fn(|i| if i < N { a.next() } else { b.next() }.unwrap())
+        let mut acc = init;
+        core::array::from_fn(|i| {
+            if i > 0 {
+                acc = f(&acc, a.next().unwrap());
+            }
+            acc.clone()
+        })
     }
 
-    #[cfg(feature = "nightly")]
-    fn split<const P: usize>(self) -> ([T; P], [T; N - P]) {
+    #[inline]
+    fn reduce<F>(self, f: F) -> Option<T>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.into_iter().reduce(f)
+    }
+
+    #[inline]
+    fn try_foldl<A, E, F>(self, acc: A, f: F) -> Result<A, E>
+    where
+        F: FnMut(A, T) -> Result<A, E>,
+    {
+        self.into_iter().try_fold(acc, f)
+    }
+
+    #[inline]
+    fn weighted_fold<A, F>(&self, mut acc: A, mut f: F) -> A
+    where
+        F: FnMut(A, usize, &T) -> A,
+    {
+        for (i, val) in self.iter().enumerate() {
+            acc = f(acc, i, val);
+        }
+        acc
+    }
+
+    #[inline]
+    fn fold_both<A, F>(&self, init: A, mut f: F) -> (A, A)
+    where
+        A: Clone,
+        F: FnMut(A, &T) -> A,
+    {
+        let mut left = init.clone();
+        let mut right = init;
+        for (a, b) in self.iter().zip(self.iter().rev()) {
+            left = f(left, a);
+            right = f(right, b);
+        }
+        (left, right)
+    }
+
+    #[inline]
+    fn try_foldl_indexed<A, E, F>(self, mut acc: A, mut f: F) -> Result<A, E>
+    where
+        F: FnMut(A, usize, T) -> Result<A, E>,
+    {
+        for (i, val) in self.into_iter().enumerate() {
+            acc = f(acc, i, val)?;
+        }
+        Ok(acc)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn rchunk_fold<const C: usize, A, F>(self, init: A, mut f: F) -> Vec<A>
+    where
+        A: Clone,
+        F: FnMut(A, T) -> A,
+    {
+        assert!(C != 0, "chunk size must be non-zero");
+        let mut res = Vec::with_capacity(N.div_ceil(C));
+        let mut acc = init.clone();
+        for (i, val) in self.into_iter().enumerate() {
+            acc = f(acc, val);
+            if (N - 1 - i).is_multiple_of(C) {
+                res.push(core::mem::replace(&mut acc, init.clone()));
+            }
+        }
+        res
+    }
+
+    #[inline]
+    fn resize<const S: usize>(self, elem: T) -> [T; S]
+    where
+        T: Clone,
+    {
+        self.resize_with(|_| elem.clone())
+    }
+
+    #[inline]
+    fn resize_with<F, const S: usize>(self, mut f: F) -> [T; S]
+    where
+        F: FnMut(usize) -> T,
+    {
         let mut a = self.into_iter();
-        let l = [(); P].map(|_| a.next().unwrap());
-        let r = [(); N - P].map(|_| a.next().unwrap());
+        core::array::from_fn(|i| if i < N { a.next().unwrap() } else { f(i) })
+    }
+
+    #[inline]
+    fn resize_with_offset<F, const S: usize>(self, mut f: F) -> [T; S]
+    where
+        F: FnMut(usize) -> T,
+    {
+        self.resize_with(|i| f(i - N))
+    }
+
+    #[inline]
+    fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        core::array::from_fn(f)
+    }
+
+    #[inline]
+    fn from_default() -> Self
+    where
+        T: Default,
+    {
+        core::array::from_fn(|_| T::default())
+    }
+
+    #[inline]
+    fn repeat(elem: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut elem = Some(elem);
+        core::array::from_fn(|i| {
+            if i + 1 == N {
+                elem.take().unwrap()
+            } else {
+                elem.clone().unwrap()
+            }
+        })
+    }
+
+    #[inline]
+    fn from_iter(mut iter: impl Iterator<Item = T>) -> Option<Self> {
+        #[cfg(feature = "nightly")]
+        {
+            core::array::try_from_fn(|_| iter.next())
+        }
+        #[cfg(not(feature = "nightly"))]
+        {
+            let mut arr = core::array::from_fn(|_| None);
+            for item in &mut arr {
+                *item = Some(iter.next()?);
+            }
+            Some(arr.map(Option::unwrap))
+        }
+    }
+
+    #[inline]
+    fn try_from_iter(mut iter: impl Iterator<Item = T>) -> Result<Self, usize> {
+        #[cfg(feature = "nightly")]
+        {
+            core::array::try_from_fn(|i| iter.next().ok_or(i))
+        }
+        #[cfg(not(feature = "nightly"))]
+        {
+            let mut arr = core::array::from_fn(|_| None);
+            for (i, item) in arr.iter_mut().enumerate() {
+                *item = Some(iter.next().ok_or(i)?);
+            }
+            Ok(arr.map(Option::unwrap))
+        }
+    }
+
+    #[inline]
+    fn from_slice(slice: &[T]) -> Option<Self>
+    where
+        T: Clone,
+    {
+        <&Self>::try_from(slice).ok().cloned()
+    }
+
+    #[inline]
+    fn try_from_fn<E, F>(mut f: F) -> Result<Self, E>
+    where
+        F: FnMut(usize) -> Result<T, E>,
+    {
+        #[cfg(feature = "nightly")]
+        {
+            core::array::try_from_fn(&mut f)
+        }
+        #[cfg(not(feature = "nightly"))]
+        {
+            let mut arr = core::array::from_fn(|_| None);
+            for (i, item) in arr.iter_mut().enumerate() {
+                *item = Some(f(i)?);
+            }
+            Ok(arr.map(Option::unwrap))
+        }
+    }
+
+    #[inline]
+    fn from_iter_padded<F>(iter: impl Iterator<Item = T>, mut pad: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        let mut iter = iter.fuse();
+        core::array::from_fn(|i| iter.next().unwrap_or_else(|| pad(i)))
+    }
+}
+
+/// Array with size information on the type.
+pub trait ArrayN<T, const N: usize>: Array<T> {
+    /// Creates a new array by calling a `FnMut(&T) -> U` closure on a reference to each element, leaving
+    /// this array untouched.
+    fn map_ref<U, F>(&self, f: F) -> [U; N]
+    where
+        F: FnMut(&T) -> U,
+        Self: Sized;
+
+    /// Creates a new array by calling a `FnMut(usize, T) -> U` closure on each element along with its index.
+    fn map_idx<U, F>(self, f: F) -> [U; N]
+    where
+        F: FnMut(usize, T) -> U,
+        Self: Sized;
+
+    /// Creates a new array by calling a `FnMut(T) -> Result<U, E>` closure on each element, stopping at the
+    /// first error.
+    ///
+    /// On error, both the elements already converted and the remaining elements are dropped.
+    fn try_map_<U, E, F>(self, f: F) -> Result<[U; N], E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+        Self: Sized;
+
+    /// Merges elements with another array by calling a `FnMut(T, U) -> Output` closure for each pair.
+    fn zip_with<U, Output, F>(self, other: [U; N], f: F) -> [Output; N]
+    where
+        F: FnMut(T, U) -> Output,
+        Self: Sized;
+
+    /// Merges elements with another array into an array of pairs.
+    fn zip_pairs<U>(self, other: [U; N]) -> [(T, U); N]
+    where
+        Self: Sized;
+
+    /// Merges elements with another array of the same type by calling a `FnMut(T, T) -> T` closure for each
+    /// pair.
+    ///
+    /// This is `zip_with` with the output restricted to the same type as the input, intended for
+    /// accumulation loops where the result replaces `self`, so the compiler can reuse its storage.
+    fn zip_into<F>(self, other: [T; N], f: F) -> [T; N]
+    where
+        F: FnMut(T, T) -> T,
+        Self: Sized;
+
+    /// Merges elements with another two arrays by calling a `FnMut(T, U, V) -> Output` closure for each tuple.
+    fn zip3_with<U, V, Output, F>(self, other1: [U; N], other2: [V; N], f: F) -> [Output; N]
+    where
+        F: FnMut(T, U, V) -> Output,
+        Self: Sized;
+
+    /// Merges elements with another three arrays by calling a `FnMut(T, U, V, W) -> Output` closure for each tuple.
+    fn zip4_with<U, V, W, Output, F>(self, other1: [U; N], other2: [V; N], other3: [W; N], f: F) -> [Output; N]
+    where
+        F: FnMut(T, U, V, W) -> Output,
+        Self: Sized;
+
+    /// Merges elements with another four arrays by calling a `FnMut(T, U, V, W, X) -> Output` closure for each tuple.
+    fn zip5_with<U, V, W, X, Output, F>(
+        self, other1: [U; N], other2: [V; N], other3: [W; N], other4: [X; N], f: F,
+    ) -> [Output; N]
+    where
+        F: FnMut(T, U, V, W, X) -> Output,
+        Self: Sized;
+
+    /// Creates a boolean mask with the result of calling the predicate on each element, in order.
+    fn mask_by<F>(&self, f: F) -> [bool; N]
+    where
+        F: FnMut(&T) -> bool,
+        Self: Sized;
+
+    /// Creates a boolean mask that is `true` where the elements are greater than the elements of `other`.
+    fn gt_mask(&self, other: &[T; N]) -> [bool; N]
+    where
+        T: PartialOrd;
+
+    /// Creates a boolean mask that is `true` where the elements are less than the elements of `other`.
+    fn lt_mask(&self, other: &[T; N]) -> [bool; N]
+    where
+        T: PartialOrd;
+
+    /// Creates a boolean mask that is `true` where the elements are equal to the elements of `other`.
+    fn eq_mask(&self, other: &[T; N]) -> [bool; N]
+    where
+        T: PartialEq;
+
+    /// Creates a new array choosing each element from this array where `mask` is `true`, or from `other`
+    /// where it's `false`.
+    fn select(self, mask: [bool; N], other: [T; N]) -> [T; N]
+    where
+        Self: Sized;
+
+    /// Merges elements with another array by calling a `FnMut(T, U) -> Result<Output, E>` closure for each
+    /// pair, stopping at the first error.
+    fn try_zip_with<U, Output, E, F>(self, other: [U; N], f: F) -> Result<[Output; N], E>
+    where
+        F: FnMut(T, U) -> Result<Output, E>,
+        Self: Sized;
+
+    /// Adds each element to the corresponding element of another array.
+    fn add_arr(self, other: [T; N]) -> [T; N]
+    where
+        T: Add<Output = T>,
+        Self: Sized;
+
+    /// Subtracts the corresponding element of another array from each element.
+    fn sub_arr(self, other: [T; N]) -> [T; N]
+    where
+        T: Sub<Output = T>,
+        Self: Sized;
+
+    /// Multiplies each element by the corresponding element of another array.
+    fn mul_arr(self, other: [T; N]) -> [T; N]
+    where
+        T: Mul<Output = T>,
+        Self: Sized;
+
+    /// Divides each element by the corresponding element of another array.
+    ///
+    /// # Panics
+    /// Panics on integer division by zero or signed integer overflow, like `i32::MIN / -1`. See
+    /// `checked_div_arr` to report them as an error instead.
+    fn div_arr(self, other: [T; N]) -> [T; N]
+    where
+        T: Div<Output = T>,
+        Self: Sized;
+
+    /// Multiplies every element by a scalar.
+    fn scale(self, k: T) -> [T; N]
+    where
+        T: Mul<Output = T> + Copy,
+        Self: Sized;
+
+    /// Adds a scalar to every element.
+    fn offset(self, k: T) -> [T; N]
+    where
+        T: Add<Output = T> + Copy,
+        Self: Sized;
+
+    /// Restricts every element to the `[min, max]` interval.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, or if either of them isn't comparable.
+    fn clamp_each(self, min: T, max: T) -> [T; N]
+    where
+        T: PartialOrd + Copy,
+        Self: Sized;
+
+    /// Computes the dot product with another array, the sum of the elementwise products.
+    ///
+    /// Returns `T::default()` for empty arrays.
+    fn dot(self, other: [T; N]) -> T
+    where
+        T: Mul<Output = T> + Add<Output = T> + Default + Copy,
+        Self: Sized;
+
+    /// Divides each element by the corresponding element of another array.
+    ///
+    /// Returns a [`DivError`] with the index of the first division that has a zero divisor or overflows.
+    fn checked_div_arr(self, other: [T; N]) -> Result<[T; N], DivError>
+    where
+        T: CheckedDiv,
+        Self: Sized;
+
+    /// Folds over references to the elements paired with another array's, without consuming either.
+    fn zip_fold_ref<U, A, F>(&self, other: &[U; N], acc: A, f: F) -> A
+    where
+        F: FnMut(A, &T, &U) -> A,
+        Self: Sized;
+
+    /// Folds the elements together with the elements of another two arrays, in ascending index order.
+    fn zip3_fold<U, V, A, F>(self, other1: [U; N], other2: [V; N], acc: A, f: F) -> A
+    where
+        F: FnMut(A, T, U, V) -> A,
+        Self: Sized;
+
+    /// Adds all the elements together. Returns zero if the array is empty.
+    fn sum<S>(self) -> S
+    where
+        S: Sum<T>,
+        Self: Sized;
+
+    /// Multiplies all the elements together. Returns one if the array is empty.
+    fn product<P>(self) -> P
+    where
+        P: Product<T>,
+        Self: Sized;
+
+    /// Merges elements with another array by calling a `FnMut(T, U) -> Option<V>` closure for each pair
+    /// until it returns `None`, collecting the `Some` results.
+    ///
+    /// The remaining elements of both arrays are dropped.
+    #[cfg(feature = "alloc")]
+    fn zip_map_while<U, V, F>(self, other: [U; N], f: F) -> Vec<V>
+    where
+        F: FnMut(T, U) -> Option<V>,
+        Self: Sized;
+
+    /// Returns clones of every pair of elements from this array and a slice, in row-major order.
+    #[cfg(feature = "alloc")]
+    fn cartesian_product<U>(&self, other: &[U]) -> Vec<(T, U)>
+    where
+        T: Clone,
+        U: Clone,
+        Self: Sized;
+
+    /// Returns the indices where the elements of this array and another array differ, in ascending order.
+    #[cfg(feature = "alloc")]
+    fn diff_indices(&self, other: &[T; N]) -> Vec<usize>
+    where
+        T: PartialEq;
+
+    /// Returns the indices where the elements of this array and another array differ, together with
+    /// both elements, in ascending order.
+    #[cfg(feature = "alloc")]
+    fn diff<'a>(&'a self, other: &'a [T; N]) -> Vec<(usize, &'a T, &'a T)>
+    where
+        T: PartialEq;
+
+    /// Computes the autocorrelation of the array with itself shifted by `lag` elements.
+    ///
+    /// The result is normalized by the variance of the whole array:
+    /// `sum((x[i] - mean) * (x[i + lag] - mean)) / sum((x[i] - mean)^2)`,
+    /// so a lag of 0 gives 1.0. Returns 0.0 if `lag >= N`, and NaN if all the elements are equal.
+    fn autocorrelation(&self, lag: usize) -> f64
+    where
+        T: Into<f64> + Copy;
+
+    /// Resamples the array into `S` evenly spaced samples using linear interpolation.
+    ///
+    /// The first and last samples are equal to the first and last elements of the array. If `S` is 1,
+    /// the only sample is the first element, and if the array is empty all the samples are NaN.
+    fn resample_linear<const S: usize>(&self) -> [f64; S]
+    where
+        T: Into<f64> + Copy,
+        Self: Sized;
+
+    /// Returns an iterator over all the overlapping windows of `W` elements.
+    ///
+    /// Yields `N - W + 1` windows, or none if `W > N`.
+    ///
+    /// # Panics
+    /// Panics if `W` is 0.
+    fn windows_iter<'a, const W: usize>(&'a self) -> impl Iterator<Item = &'a [T; W]>
+    where
+        T: 'a,
+        Self: Sized;
+
+    /// Returns an array with clones of all the overlapping windows of `W` elements.
+    ///
+    /// There are `N - W + 1` windows, or none if `W > N`.
+    ///
+    /// # Panics
+    /// Panics if `W` is 0, or if `M` isn't equal to the number of windows.
+    fn windows_array<const W: usize, const M: usize>(&self) -> [[T; W]; M]
+    where
+        T: Clone,
+        Self: Sized;
+
+    /// Reverses the order of the elements in place.
+    fn reverse(&mut self);
+
+    /// Returns a new array with the elements in reverse order.
+    fn reversed(self) -> [T; N]
+    where
+        Self: Sized;
+
+    /// Returns a new array with the elements sorted.
+    ///
+    /// This sort is stable, see [`slice::sort`].
+    #[cfg(feature = "alloc")]
+    fn sorted(self) -> [T; N]
+    where
+        T: Ord,
+        Self: Sized;
+
+    /// Returns a new array with the elements rotated `K` places to the left.
+    ///
+    /// `K` is taken modulo `N`, and an empty array is returned unchanged.
+    fn rotated_left<const K: usize>(self) -> [T; N]
+    where
+        Self: Sized;
+
+    /// Returns a new array with the elements rotated `K` places to the right.
+    ///
+    /// `K` is taken modulo `N`, and an empty array is returned unchanged.
+    fn rotated_right<const K: usize>(self) -> [T; N]
+    where
+        Self: Sized;
+
+    /// Returns the lexicographically smallest rotation of the array, which starts at a minimum element.
+    ///
+    /// Arrays that are rotations of each other give the same result.
+    fn rotate_to_min(self) -> [T; N]
+    where
+        T: Ord,
+        Self: Sized;
+
+    /// Returns a reference to the element at the given index.
+    ///
+    /// # Panics
+    /// Panics if `index >= N`.
+    fn at(&self, index: usize) -> &T;
+
+    /// Returns a mutable reference to the element at the given index.
+    ///
+    /// # Panics
+    /// Panics if `index >= N`.
+    fn at_mut(&mut self, index: usize) -> &mut T;
+
+    /// Returns a reference to the element at the given index, or an [`OutOfBounds`] error.
+    fn try_at(&self, index: usize) -> Result<&T, OutOfBounds>;
+
+    /// Returns a mutable reference to the element at the given index, or an [`OutOfBounds`] error.
+    fn try_at_mut(&mut self, index: usize) -> Result<&mut T, OutOfBounds>;
+
+    /// Converts this object into it's concrete array type.
+    fn downcast(self) -> [T; N];
+
+    /// Gets a reference to this object's concrete array type.
+    fn downcast_ref(&self) -> &[T; N];
+
+    /// Gets a mutable reference to this object's concrete array type.
+    fn downcast_mut(&mut self) -> &mut [T; N];
+
+    /// Creates an array of uninitialized elements.
+    ///
+    /// Use [`assume_init`](ArrayN::assume_init) to convert it into a regular array after writing
+    /// every element.
+    fn uninit() -> [MaybeUninit<T>; N]
+    where
+        Self: Sized;
+
+    /// Converts an array of `MaybeUninit` elements into an array of initialized elements.
+    ///
+    /// # Safety
+    /// Every element of `arr` must be fully initialized. `MaybeUninit` never drops its contents, so if
+    /// the array is abandoned before calling this, the caller is responsible for dropping the elements
+    /// that were already written.
+    unsafe fn assume_init(arr: [MaybeUninit<T>; N]) -> [T; N]
+    where
+        Self: Sized;
+
+    /// Concatenates two arrays together.
+    #[cfg(feature = "nightly")]
+    fn concat<const M: usize>(self, other: [T; M]) -> [T; N + M]
+    where
+        Self: Sized;
+
+    /// Splits an array into two sub-arrays.
+    #[cfg(feature = "nightly")]
+    fn split<const P: usize>(self) -> ([T; P], [T; N - P])
+    where
+        Self: Sized;
+
+    /// Appends an element to the end of the array.
+    #[cfg(feature = "nightly")]
+    fn push(self, elem: T) -> [T; N + 1]
+    where
+        Self: Sized;
+
+    /// Removes the last element, returning it along with the remaining array.
+    ///
+    /// Fails to compile if the array is empty.
+    #[cfg(feature = "nightly")]
+    fn pop(self) -> ([T; N - 1], T)
+    where
+        Self: Sized;
+
+    /// Inserts an element at position `I`, shifting all the elements after it to the right.
+    ///
+    /// Fails to compile if `I > N`.
+    #[cfg(feature = "nightly")]
+    fn insert<const I: usize>(self, elem: T) -> [T; N + 1]
+    where
+        [(); N - I]:,
+        Self: Sized;
+
+    /// Removes the element at position `I`, returning it along with the remaining array. All the elements
+    /// after it are shifted to the left.
+    ///
+    /// Fails to compile if `I >= N`.
+    #[cfg(feature = "nightly")]
+    fn remove<const I: usize>(self) -> ([T; N - 1], T)
+    where
+        [(); N - I - 1]:,
+        Self: Sized;
+
+    /// Splits off the first element, returning it along with the remaining array.
+    ///
+    /// Fails to compile if the array is empty.
+    #[cfg(feature = "nightly")]
+    fn split_first_owned(self) -> (T, [T; N - 1])
+    where
+        Self: Sized;
+
+    /// Splits off the last element, returning the remaining array along with it.
+    ///
+    /// This is the same as `pop`. Fails to compile if the array is empty.
+    #[cfg(feature = "nightly")]
+    fn split_last_owned(self) -> ([T; N - 1], T)
+    where
+        Self: Sized;
+
+    /// Returns the first `M` elements of the array, dropping the rest.
+    ///
+    /// Fails to compile if `M > N`.
+    #[cfg(feature = "nightly")]
+    fn take<const M: usize>(self) -> [T; M]
+    where
+        [(); N - M]:,
+        Self: Sized;
+
+    /// Returns the array without its first `M` elements, which are dropped.
+    ///
+    /// Fails to compile if `M > N`.
+    #[cfg(feature = "nightly")]
+    fn drop<const M: usize>(self) -> [T; N - M]
+    where
+        Self: Sized;
+
+    /// Creates a new array with the results of calling a function on each window of `W` elements.
+    ///
+    /// `W` can't be greater than `N`, otherwise it fails to compile.
+    #[cfg(feature = "nightly")]
+    fn aggregate_windows<const W: usize, A, F>(&self, f: F) -> [A; N - W + 1]
+    where
+        F: FnMut(&[T; W]) -> A,
+        Self: Sized;
+
+    /// Computes the population variance of each window of `W` elements.
+    ///
+    /// The variance of the first window is computed with Welford's algorithm, and then updated
+    /// incrementally as the window slides, so each step is O(1) regardless of `W`. `W` can't be
+    /// greater than `N`, otherwise it fails to compile.
+    ///
+    /// # Panics
+    /// Panics if `W` is 0.
+    #[cfg(feature = "nightly")]
+    fn rolling_variance<const W: usize>(&self) -> [f64; N - W + 1]
+    where
+        T: Into<f64> + Copy,
+        Self: Sized;
+
+    /// Reshapes the array into a row-major `R x C` 2D array.
+    ///
+    /// `R * C` must be equal to `N`, otherwise it fails to compile.
+    #[cfg(feature = "nightly")]
+    fn reshape<const R: usize, const C: usize>(self) -> [[T; C]; R]
+    where
+        [(); R * C - N]:,
+        [(); N - R * C]:,
+        Self: Sized;
+
+    /// Splits the array into `N / C` consecutive chunks of `C` elements.
+    ///
+    /// `N` must be a multiple of `C`, otherwise it fails to compile.
+    #[cfg(feature = "nightly")]
+    fn chunks_array<const C: usize>(self) -> [[T; C]; N / C]
+    where
+        [(); 0 - N % C]:,
+        Self: Sized;
+}
+
+impl<T, const N: usize> ArrayN<T, N> for [T; N] {
+    #[inline]
+    fn map_ref<U, F>(&self, f: F) -> [U; N]
+    where
+        F: FnMut(&T) -> U,
+    {
+        self.each_ref().map(f)
+    }
+
+    #[inline]
+    fn map_idx<U, F>(self, mut f: F) -> [U; N]
+    where
+        F: FnMut(usize, T) -> U,
+    {
+        let mut i = 0;
+        self.map(|x| {
+            let r = f(i, x);
+            i += 1;
+            r
+        })
+    }
+
+    #[inline]
+    fn try_map_<U, E, F>(self, mut f: F) -> Result<[U; N], E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        let mut a = self.into_iter();
+        Array::try_from_fn(|_| f(a.next().unwrap()))
+    }
+
+    #[inline]
+    fn zip_with<U, Output, F>(self, other: [U; N], mut f: F) -> [Output; N]
+    where
+        F: FnMut(T, U) -> Output,
+    {
+        let mut b = other.into_iter();
+        self.map(|a| f(a, b.next().unwrap()))
+    }
+
+    #[inline]
+    fn zip_pairs<U>(self, other: [U; N]) -> [(T, U); N] {
+        self.zip_with(other, |a, b| (a, b))
+    }
+
+    #[inline]
+    fn zip_into<F>(self, other: [T; N], f: F) -> [T; N]
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.zip_with(other, f)
+    }
+
+    #[inline]
+    fn zip3_with<U, V, Output, F>(self, other1: [U; N], other2: [V; N], mut f: F) -> [Output; N]
+    where
+        F: FnMut(T, U, V) -> Output,
+    {
+        let mut it1 = other1.into_iter();
+        let mut it2 = other2.into_iter();
+        self.map(|x0| f(x0, it1.next().unwrap(), it2.next().unwrap()))
+    }
+
+    #[inline]
+    fn zip4_with<U, V, W, Output, F>(self, other1: [U; N], other2: [V; N], other3: [W; N], mut f: F) -> [Output; N]
+    where
+        F: FnMut(T, U, V, W) -> Output,
+    {
+        let mut it1 = other1.into_iter();
+        let mut it2 = other2.into_iter();
+        let mut it3 = other3.into_iter();
+        self.map(|x0| f(x0, it1.next().unwrap(), it2.next().unwrap(), it3.next().unwrap()))
+    }
+
+    #[inline]
+    fn zip5_with<U, V, W, X, Output, F>(
+        self, other1: [U; N], other2: [V; N], other3: [W; N], other4: [X; N], mut f: F,
+    ) -> [Output; N]
+    where
+        F: FnMut(T, U, V, W, X) -> Output,
+    {
+        let mut it1 = other1.into_iter();
+        let mut it2 = other2.into_iter();
+        let mut it3 = other3.into_iter();
+        let mut it4 = other4.into_iter();
+        self.map(|x0| {
+            f(
+                x0,
+                it1.next().unwrap(),
+                it2.next().unwrap(),
+                it3.next().unwrap(),
+                it4.next().unwrap(),
+            )
+        })
+    }
+
+    #[inline]
+    fn mask_by<F>(&self, f: F) -> [bool; N]
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.each_ref().map(f)
+    }
+
+    #[inline]
+    fn gt_mask(&self, other: &[T; N]) -> [bool; N]
+    where
+        T: PartialOrd,
+    {
+        core::array::from_fn(|i| self[i] > other[i])
+    }
+
+    #[inline]
+    fn lt_mask(&self, other: &[T; N]) -> [bool; N]
+    where
+        T: PartialOrd,
+    {
+        core::array::from_fn(|i| self[i] < other[i])
+    }
+
+    #[inline]
+    fn eq_mask(&self, other: &[T; N]) -> [bool; N]
+    where
+        T: PartialEq,
+    {
+        core::array::from_fn(|i| self[i] == other[i])
+    }
+
+    #[inline]
+    fn select(self, mask: [bool; N], other: [T; N]) -> [T; N] {
+        self.zip3_with(mask, other, |a, m, b| if m { a } else { b })
+    }
+
+    #[inline]
+    fn try_zip_with<U, Output, E, F>(self, other: [U; N], mut f: F) -> Result<[Output; N], E>
+    where
+        F: FnMut(T, U) -> Result<Output, E>,
+    {
+        let mut arr = core::array::from_fn(|_| None);
+        for (item, (a, b)) in arr.iter_mut().zip(self.into_iter().zip(other)) {
+            *item = Some(f(a, b)?);
+        }
+        Ok(arr.map(Option::unwrap))
+    }
+
+    #[inline]
+    fn add_arr(self, other: [T; N]) -> [T; N]
+    where
+        T: Add<Output = T>,
+    {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    #[inline]
+    fn sub_arr(self, other: [T; N]) -> [T; N]
+    where
+        T: Sub<Output = T>,
+    {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    #[inline]
+    fn mul_arr(self, other: [T; N]) -> [T; N]
+    where
+        T: Mul<Output = T>,
+    {
+        self.zip_with(other, |a, b| a * b)
+    }
+
+    #[inline]
+    fn div_arr(self, other: [T; N]) -> [T; N]
+    where
+        T: Div<Output = T>,
+    {
+        self.zip_with(other, |a, b| a / b)
+    }
+
+    #[inline]
+    fn scale(self, k: T) -> [T; N]
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        self.map(|a| a * k)
+    }
+
+    #[inline]
+    fn offset(self, k: T) -> [T; N]
+    where
+        T: Add<Output = T> + Copy,
+    {
+        self.map(|a| a + k)
+    }
+
+    #[inline]
+    fn clamp_each(self, min: T, max: T) -> [T; N]
+    where
+        T: PartialOrd + Copy,
+    {
+        assert!(min <= max, "clamp_each requires min <= max");
+        self.map(|x| {
+            if x < min {
+                min
+            } else if x > max {
+                max
+            } else {
+                x
+            }
+        })
+    }
+
+    #[inline]
+    fn dot(self, other: [T; N]) -> T
+    where
+        T: Mul<Output = T> + Add<Output = T> + Default + Copy,
+    {
+        self.into_iter()
+            .zip(other)
+            .fold(T::default(), |acc, (a, b)| acc + a * b)
+    }
+
+    #[inline]
+    fn checked_div_arr(self, other: [T; N]) -> Result<[T; N], DivError>
+    where
+        T: CheckedDiv,
+    {
+        let mut index = 0;
+        self.try_zip_with(other, |a, b| {
+            let kind = if b.is_zero() {
+                DivErrorKind::DivByZero
+            } else {
+                DivErrorKind::Overflow
+            };
+            let res = a.checked_div(b).ok_or(DivError { index, kind });
+            index += 1;
+            res
+        })
+    }
+
+    #[inline]
+    fn zip_fold_ref<U, A, F>(&self, other: &[U; N], mut acc: A, mut f: F) -> A
+    where
+        F: FnMut(A, &T, &U) -> A,
+    {
+        for (a, b) in self.iter().zip(other) {
+            acc = f(acc, a, b);
+        }
+        acc
+    }
+
+    #[inline]
+    fn zip3_fold<U, V, A, F>(self, other1: [U; N], other2: [V; N], mut acc: A, mut f: F) -> A
+    where
+        F: FnMut(A, T, U, V) -> A,
+    {
+        for ((x0, x1), x2) in self.into_iter().zip(other1).zip(other2) {
+            acc = f(acc, x0, x1, x2);
+        }
+        acc
+    }
+
+    #[inline]
+    fn sum<S>(self) -> S
+    where
+        S: Sum<T>,
+    {
+        self.into_iter().sum()
+    }
+
+    #[inline]
+    fn product<P>(self) -> P
+    where
+        P: Product<T>,
+    {
+        self.into_iter().product()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn zip_map_while<U, V, F>(self, other: [U; N], mut f: F) -> Vec<V>
+    where
+        F: FnMut(T, U) -> Option<V>,
+    {
+        self.into_iter().zip(other).map_while(|(a, b)| f(a, b)).collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn cartesian_product<U>(&self, other: &[U]) -> Vec<(T, U)>
+    where
+        T: Clone,
+        U: Clone,
+    {
+        self.iter()
+            .flat_map(|a| other.iter().map(move |b| (a.clone(), b.clone())))
+            .collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn diff_indices(&self, other: &[T; N]) -> Vec<usize>
+    where
+        T: PartialEq,
+    {
+        (0..N).filter(|&i| self[i] != other[i]).collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn diff<'a>(&'a self, other: &'a [T; N]) -> Vec<(usize, &'a T, &'a T)>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .zip(other)
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(i, (a, b))| (i, a, b))
+            .collect()
+    }
+
+    fn autocorrelation(&self, lag: usize) -> f64
+    where
+        T: Into<f64> + Copy,
+    {
+        if lag >= N {
+            return 0.0;
+        }
+        let x = self.map(Into::into);
+        let mean = x.iter().sum::<f64>() / N as f64;
+        let var = x.iter().map(|a| (a - mean) * (a - mean)).sum::<f64>();
+        let cov = x
+            .iter()
+            .zip(&x[lag..])
+            .map(|(a, b)| (a - mean) * (b - mean))
+            .sum::<f64>();
+        cov / var
+    }
+
+    fn resample_linear<const S: usize>(&self) -> [f64; S]
+    where
+        T: Into<f64> + Copy,
+    {
+        if N == 0 {
+            return [f64::NAN; S];
+        }
+        let x = self.map(Into::into);
+        let div = S.saturating_sub(1).max(1);
+        core::array::from_fn(|i| {
+            // exact integer position, so the samples that land on an element don't pick up rounding errors
+            let pos = i * (N - 1);
+            let (lo, rem) = (pos / div, pos % div);
+            let hi = (lo + 1).min(N - 1);
+            x[lo] + (x[hi] - x[lo]) * (rem as f64 / div as f64)
+        })
+    }
+
+    #[inline]
+    fn windows_iter<'a, const W: usize>(&'a self) -> impl Iterator<Item = &'a [T; W]>
+    where
+        T: 'a,
+    {
+        self.as_slice().windows(W).map(|w| w.try_into().unwrap())
+    }
+
+    #[inline]
+    fn windows_array<const W: usize, const M: usize>(&self) -> [[T; W]; M]
+    where
+        T: Clone,
+    {
+        assert!(W != 0, "window size must be non-zero");
+        assert_eq!(
+            M,
+            (N + 1).saturating_sub(W),
+            "windows output size must be the number of windows"
+        );
+        core::array::from_fn(|i| core::array::from_fn(|j| self[i + j].clone()))
+    }
+
+    #[inline]
+    fn reverse(&mut self) {
+        self.as_mut_slice().reverse()
+    }
+
+    #[inline]
+    fn reversed(mut self) -> [T; N] {
+        self.as_mut_slice().reverse();
+        self
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn sorted(mut self) -> [T; N]
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().sort();
+        self
+    }
+
+    #[inline]
+    fn rotated_left<const K: usize>(mut self) -> [T; N] {
+        if N != 0 {
+            self.as_mut_slice().rotate_left(K % N);
+        }
+        self
+    }
+
+    #[inline]
+    fn rotated_right<const K: usize>(mut self) -> [T; N] {
+        if N != 0 {
+            self.as_mut_slice().rotate_right(K % N);
+        }
+        self
+    }
+
+    fn rotate_to_min(mut self) -> [T; N]
+    where
+        T: Ord,
+    {
+        let rotation = |k: usize| self[k..].iter().chain(&self[..k]);
+        let best = (1..N).fold(0, |best, k| if rotation(k).lt(rotation(best)) { k } else { best });
+        self.as_mut_slice().rotate_left(best);
+        self
+    }
+
+    #[inline]
+    fn at(&self, index: usize) -> &T {
+        &self[index]
+    }
+
+    #[inline]
+    fn at_mut(&mut self, index: usize) -> &mut T {
+        &mut self[index]
+    }
+
+    #[inline]
+    fn try_at(&self, index: usize) -> Result<&T, OutOfBounds> {
+        Array::get(self, index).ok_or(OutOfBounds { index, len: N })
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, index: usize) -> Result<&mut T, OutOfBounds> {
+        Array::get_mut(self, index).ok_or(OutOfBounds { index, len: N })
+    }
+
+    #[inline]
+    fn downcast(self) -> [T; N] {
+        self
+    }
+
+    #[inline]
+    fn downcast_ref(&self) -> &[T; N] {
+        self
+    }
+
+    #[inline]
+    fn downcast_mut(&mut self) -> &mut [T; N] {
+        self
+    }
+
+    #[inline]
+    fn uninit() -> [MaybeUninit<T>; N] {
+        [const { MaybeUninit::uninit() }; N]
+    }
+
+    #[inline]
+    unsafe fn assume_init(arr: [MaybeUninit<T>; N]) -> [T; N] {
+        // SAFETY: the caller guarantees that every element is initialized
+        arr.map(|x| unsafe { x.assume_init() })
+    }
+
+    #[cfg(feature = "nightly")]
+    fn concat<const M: usize>(self, other: [T; M]) -> [T; N + M] {
+        let mut a = self.into_iter();
+        let mut b = other.into_iter();
+        core::array::from_fn(|i| if i < N { a.next() } else { b.next() }.unwrap())
+    }
+
+    #[cfg(feature = "nightly")]
+    fn push(self, elem: T) -> [T; N + 1] {
+        let mut a = self.into_iter();
+        let mut elem = Some(elem);
+        core::array::from_fn(|_| a.next().or_else(|| elem.take()).unwrap())
+    }
+
+    #[cfg(feature = "nightly")]
+    fn pop(self) -> ([T; N - 1], T) {
+        let mut a = self.into_iter();
+        let rest = core::array::from_fn(|_| a.next().unwrap());
+        (rest, a.next().unwrap())
+    }
+
+    #[cfg(feature = "nightly")]
+    fn insert<const I: usize>(self, elem: T) -> [T; N + 1]
+    where
+        [(); N - I]:,
+    {
+        let mut a = self.into_iter();
+        let mut elem = Some(elem);
+        core::array::from_fn(|i| if i == I { elem.take() } else { a.next() }.unwrap())
+    }
+
+    #[cfg(feature = "nightly")]
+    fn remove<const I: usize>(self) -> ([T; N - 1], T)
+    where
+        [(); N - I - 1]:,
+    {
+        let mut a = self.into_iter();
+        let mut elem = None;
+        let rest = core::array::from_fn(|i| {
+            if i == I {
+                elem = a.next();
+            }
+            a.next().unwrap()
+        });
+        (rest, elem.or_else(|| a.next()).unwrap())
+    }
+
+    #[cfg(feature = "nightly")]
+    fn split_first_owned(self) -> (T, [T; N - 1]) {
+        let mut a = self.into_iter();
+        let first = a.next().unwrap();
+        (first, core::array::from_fn(|_| a.next().unwrap()))
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn split_last_owned(self) -> ([T; N - 1], T) {
+        self.pop()
+    }
+
+    #[cfg(feature = "nightly")]
+    fn take<const M: usize>(self) -> [T; M]
+    where
+        [(); N - M]:,
+    {
+        let mut a = self.into_iter();
+        core::array::from_fn(|_| a.next().unwrap())
+    }
+
+    #[cfg(feature = "nightly")]
+    fn drop<const M: usize>(self) -> [T; N - M] {
+        let mut a = self.into_iter();
+        a.by_ref().take(M).for_each(drop);
+        core::array::from_fn(|_| a.next().unwrap())
+    }
+
+    #[cfg(feature = "nightly")]
+    fn split<const P: usize>(self) -> ([T; P], [T; N - P]) {
+        let mut a = self.into_iter();
+        let l = [(); P].map(|_| a.next().unwrap());
+        let r = [(); N - P].map(|_| a.next().unwrap());
         (l, r)
     }
+
+    #[cfg(feature = "nightly")]
+    fn aggregate_windows<const W: usize, A, F>(&self, mut f: F) -> [A; N - W + 1]
+    where
+        F: FnMut(&[T; W]) -> A,
+    {
+        core::array::from_fn(|i| f(self[i..i + W].try_into().unwrap()))
+    }
+
+    #[cfg(feature = "nightly")]
+    fn rolling_variance<const W: usize>(&self) -> [f64; N - W + 1]
+    where
+        T: Into<f64> + Copy,
+    {
+        assert!(W != 0, "window size must be non-zero");
+        let x = self.map(Into::into);
+        let w = W as f64;
+        let (mut mean, mut m2) = (0.0, 0.0);
+        for (k, &val) in x[..W].iter().enumerate() {
+            let delta = val - mean;
+            mean += delta / (k + 1) as f64;
+            m2 += delta * (val - mean);
+        }
+        core::array::from_fn(|i| {
+            if i > 0 {
+                let (old, new) = (x[i - 1], x[i + W - 1]);
+                let new_mean = mean + (new - old) / w;
+                m2 += (new - old) * (new - new_mean + old - mean);
+                mean = new_mean;
+            }
+            m2.max(0.0) / w
+        })
+    }
+
+    #[cfg(feature = "nightly")]
+    fn reshape<const R: usize, const C: usize>(self) -> [[T; C]; R]
+    where
+        [(); R * C - N]:,
+        [(); N - R * C]:,
+    {
+        let mut a = self.into_iter();
+        [(); R].map(|_| [(); C].map(|_| a.next().unwrap()))
+    }
+
+    #[cfg(feature = "nightly")]
+    fn chunks_array<const C: usize>(self) -> [[T; C]; N / C]
+    where
+        [(); 0 - N % C]:,
+    {
+        let mut a = self.into_iter();
+        core::array::from_fn(|_| core::array::from_fn(|_| a.next().unwrap()))
+    }
+}
+
+/// Array length as an associated constant.
+///
+/// This is separate from `ArrayN` because associated constants would make it not dyn-compatible.
+pub trait ConstLen {
+    /// The number of elements in the array.
+    const LEN: usize;
+}
+
+impl<T, const N: usize> ConstLen for [T; N] {
+    const LEN: usize = N;
+}
+
+/// Error returned when accessing an array with an out of bounds index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfBounds {
+    /// The index used to access the array.
+    pub index: usize,
+    /// The length of the array.
+    pub len: usize,
+}
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "index {} is out of bounds for array of length {}",
+            self.index, self.len
+        )
+    }
+}
+
+impl core::error::Error for OutOfBounds {}
+
+/// Error returned when an elementwise integer division fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DivError {
+    /// The index of the failed division.
+    pub index: usize,
+    /// The reason the division failed.
+    pub kind: DivErrorKind,
+}
+
+/// The reason an integer division failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivErrorKind {
+    /// The divisor was zero.
+    DivByZero,
+    /// The result doesn't fit in the type, as in `i32::MIN / -1`.
+    Overflow,
+}
+
+impl fmt::Display for DivError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            DivErrorKind::DivByZero => write!(f, "division by zero at index {}", self.index),
+            DivErrorKind::Overflow => write!(f, "division overflow at index {}", self.index),
+        }
+    }
+}
+
+impl core::error::Error for DivError {}
+
+/// Primitive integer types with checked division, used by [`ArrayN::checked_div_arr`].
+pub trait CheckedDiv: Sized {
+    /// Divides `self` by `rhs`, returning `None` if `rhs` is zero or the division overflows.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+
+    /// Returns `true` if the value is zero.
+    fn is_zero(&self) -> bool;
+}
+
+macro_rules! impl_checked_div {
+    ($($t:ty),+) => {$(
+        impl CheckedDiv for $t {
+            #[inline]
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_div(self, rhs)
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+        }
+    )+};
+}
+
+impl_checked_div!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Array of `Option` values.
+pub trait OptionArray<T, const N: usize>: ArrayN<Option<T>, N> {
+    /// Converts an array of `Option`s into an `Option` of an array.
+    ///
+    /// Returns `Some` if all of the elements are `Some`, otherwise returns `None`.
+    fn transpose_options(self) -> Option<[T; N]>
+    where
+        Self: Sized;
+
+    /// Collects the `Some` values in order, discarding the `None`s.
+    #[cfg(feature = "alloc")]
+    fn flatten_options(self) -> Vec<T>
+    where
+        Self: Sized;
+
+    /// Collects the `Some` values in order, and counts the number of `None`s.
+    #[cfg(feature = "alloc")]
+    fn partition_options(self) -> (Vec<T>, usize)
+    where
+        Self: Sized;
+}
+
+impl<T, const N: usize> OptionArray<T, N> for [Option<T>; N] {
+    #[inline]
+    fn transpose_options(self) -> Option<[T; N]> {
+        Array::from_iter(self.into_iter().map_while(|x| x))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn flatten_options(self) -> Vec<T> {
+        self.into_iter().flatten().collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn partition_options(self) -> (Vec<T>, usize) {
+        let present = self.flatten_options();
+        let missing = N - present.len();
+        (present, missing)
+    }
+}
+
+/// Array of `Result` values.
+pub trait ResultArray<T, E, const N: usize>: ArrayN<Result<T, E>, N> {
+    /// Converts an array of `Result`s into a `Result` of an array.
+    ///
+    /// Returns `Ok` if all of the elements are `Ok`, otherwise returns the first `Err`.
+    fn transpose_results(self) -> Result<[T; N], E>
+    where
+        Self: Sized;
+}
+
+impl<T, E, const N: usize> ResultArray<T, E, N> for [Result<T, E>; N] {
+    #[inline]
+    fn transpose_results(self) -> Result<[T; N], E> {
+        let mut arr = core::array::from_fn(|_| None);
+        for (item, res) in arr.iter_mut().zip(self) {
+            *item = Some(res?);
+        }
+        Ok(arr.map(Option::unwrap))
+    }
+}
+
+/// Array of booleans.
+pub trait BoolArray<const N: usize>: ArrayN<bool, N> {
+    /// Returns the number of `true` elements.
+    fn count_true(&self) -> usize;
+
+    /// Returns the number of `false` elements.
+    fn count_false(&self) -> usize;
+}
+
+impl<const N: usize> BoolArray<N> for [bool; N] {
+    #[inline]
+    fn count_true(&self) -> usize {
+        self.iter().filter(|&&b| b).count()
+    }
+
+    #[inline]
+    fn count_false(&self) -> usize {
+        N - self.count_true()
+    }
+}
+
+/// Array of triples.
+pub trait TripleArray<A, B, C, const N: usize>: ArrayN<(A, B, C), N> {
+    /// Splits an array of triples into three arrays with the components of each triple.
+    fn unzip3(self) -> ([A; N], [B; N], [C; N])
+    where
+        Self: Sized;
+}
+
+impl<A, B, C, const N: usize> TripleArray<A, B, C, N> for [(A, B, C); N] {
+    #[inline]
+    fn unzip3(self) -> ([A; N], [B; N], [C; N]) {
+        let mut bs = [(); N].map(|_| None);
+        let mut cs = [(); N].map(|_| None);
+        let mut i = 0;
+        let a = self.map(|(a, b, c)| {
+            bs[i] = Some(b);
+            cs[i] = Some(c);
+            i += 1;
+            a
+        });
+        (a, bs.map(Option::unwrap), cs.map(Option::unwrap))
+    }
+}
+
+/// Array of three elements.
+pub trait Array3<T>: ArrayN<T, 3> {
+    /// Computes the right-handed cross product with another 3-element array.
+    fn cross(self, other: [T; 3]) -> [T; 3]
+    where
+        T: Mul<Output = T> + Sub<Output = T> + Copy,
+        Self: Sized;
+}
+
+impl<T> Array3<T> for [T; 3] {
+    #[inline]
+    fn cross(self, other: [T; 3]) -> [T; 3]
+    where
+        T: Mul<Output = T> + Sub<Output = T> + Copy,
+    {
+        let [a1, a2, a3] = self;
+        let [b1, b2, b3] = other;
+        [a2 * b3 - a3 * b2, a3 * b1 - a1 * b3, a1 * b2 - a2 * b1]
+    }
+}
+
+/// Array of floating point numbers.
+#[cfg(feature = "std")]
+pub trait FloatArray<T, const N: usize>: ArrayN<T, N> {
+    /// Returns the Euclidean length of the array, the square root of the sum of squares.
+    fn magnitude(&self) -> T;
+
+    /// Returns the array divided by its magnitude, so it has a length of 1.
+    ///
+    /// An array with magnitude 0 is returned as all zeros.
+    fn normalized(self) -> [T; N]
+    where
+        Self: Sized;
+
+    /// Computes the absolute value of each element.
+    fn abs(self) -> [T; N]
+    where
+        Self: Sized;
+
+    /// Rounds each element down to the nearest integer.
+    fn floor(self) -> [T; N]
+    where
+        Self: Sized;
+
+    /// Rounds each element up to the nearest integer.
+    fn ceil(self) -> [T; N]
+    where
+        Self: Sized;
+
+    /// Rounds each element to the nearest integer, with half-way cases rounded away from zero.
+    fn round(self) -> [T; N]
+    where
+        Self: Sized;
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_float_array {
+    ($($t:ty),+) => {$(
+        impl<const N: usize> FloatArray<$t, N> for [$t; N] {
+            #[inline]
+            fn magnitude(&self) -> $t {
+                self.iter().map(|x| x * x).sum::<$t>().sqrt()
+            }
+
+            #[inline]
+            fn normalized(self) -> [$t; N] {
+                let m = self.magnitude();
+                if m == 0.0 {
+                    [0.0; N]
+                } else {
+                    self.map(|x| x / m)
+                }
+            }
+
+            #[inline]
+            fn abs(self) -> [$t; N] {
+                self.map(<$t>::abs)
+            }
+
+            #[inline]
+            fn floor(self) -> [$t; N] {
+                self.map(<$t>::floor)
+            }
+
+            #[inline]
+            fn ceil(self) -> [$t; N] {
+                self.map(<$t>::ceil)
+            }
+
+            #[inline]
+            fn round(self) -> [$t; N] {
+                self.map(<$t>::round)
+            }
+        }
+    )+};
+}
+
+#[cfg(feature = "std")]
+impl_float_array!(f32, f64);
+
+/// Array of arrays.
+pub trait NestedArray<T, const N: usize, const M: usize>: ArrayN<[T; M], N> {
+    /// Transposes a `N x M` array of arrays into a `M x N` one, moving the elements.
+    fn transpose(self) -> [[T; N]; M]
+    where
+        Self: Sized;
+
+    /// Concatenates the inner arrays into a flat `[T; N * M]` array, moving the elements.
+    #[cfg(feature = "nightly")]
+    fn flatten(self) -> [T; N * M]
+    where
+        Self: Sized;
+
+    /// Gets a reference to the nested array as a flat `[T; N * M]` array.
+    #[cfg(feature = "nightly")]
+    fn flatten_ref(&self) -> &[T; N * M];
+}
+
+impl<T, const N: usize, const M: usize> NestedArray<T, N, M> for [[T; M]; N] {
+    #[inline]
+    fn transpose(self) -> [[T; N]; M] {
+        let mut rows = self.map(IntoIterator::into_iter);
+        core::array::from_fn(|_| rows.each_mut().map(|row| row.next().unwrap()))
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn flatten(self) -> [T; N * M] {
+        let mut a = self.into_iter().flatten();
+        core::array::from_fn(|_| a.next().unwrap())
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn flatten_ref(&self) -> &[T; N * M] {
+        // SAFETY: `[[T; M]; N]` has the same size and layout as `[T; N * M]`
+        unsafe { &*(self as *const Self as *const [T; N * M]) }
+    }
+}
+
+/// Conversion between arrays and homogeneous tuples.
+pub trait IntoTuple {
+    /// The tuple type with the same number and type of elements.
+    type Tuple;
+
+    /// Converts the array into a tuple.
+    fn into_tuple(self) -> Self::Tuple;
+
+    /// Creates an array from a tuple.
+    fn from_tuple(tuple: Self::Tuple) -> Self;
+}
+
+macro_rules! impl_into_tuple {
+    (@ty $_:ident $t:ty) => { $t };
+    ($($n:literal => ($($x:ident),+);)+) => {$(
+        impl<T> IntoTuple for [T; $n] {
+            type Tuple = ($(impl_into_tuple!(@ty $x T),)+);
+
+            #[inline]
+            fn into_tuple(self) -> Self::Tuple {
+                let [$($x),+] = self;
+                ($($x,)+)
+            }
+
+            #[inline]
+            fn from_tuple(($($x,)+): Self::Tuple) -> Self {
+                [$($x),+]
+            }
+        }
+    )+};
+}
+
+impl_into_tuple! {
+    2 => (a, b);
+    3 => (a, b, c);
+    4 => (a, b, c, d);
+    5 => (a, b, c, d, e);
+    6 => (a, b, c, d, e, f);
+    7 => (a, b, c, d, e, f, g);
+    8 => (a, b, c, d, e, f, g, h);
+}
+
+/// Interleaves the elements of `K` arrays, taking one element from each array in turn.
+///
+/// The output starts with the first element of every array in order, then the second element of every
+/// array, and so on: `[a0, b0, c0, a1, b1, c1, ...]`.
+#[cfg(feature = "nightly")]
+pub fn interleave_n<T, const K: usize, const N: usize>(arrays: [[T; N]; K]) -> [T; K * N] {
+    let mut iters = arrays.map(IntoIterator::into_iter);
+    core::array::from_fn(|i| iters[i % K].next().unwrap())
+}
+
+/// Splits an array into `K` arrays, distributing the elements to each array in turn.
+///
+/// This is the inverse of [`interleave_n`]: element `i` goes to position `i / K` of array `i % K`.
+#[cfg(feature = "nightly")]
+pub fn deinterleave<T, const K: usize, const N: usize>(arr: [T; K * N]) -> [[T; N]; K] {
+    let mut res = [(); K].map(|_| [(); N].map(|_| None));
+    for (i, val) in arr.into_iter().enumerate() {
+        res[i % K][i / K] = Some(val);
+    }
+    res.map(|a| a.map(Option::unwrap))
 }
 
 #[cfg(test)]
@@ -1,27 +1,35 @@
 use super::*;
+use std::boxed::Box;
+use std::cell::Cell;
 use std::fmt::Debug;
 use std::ops::Add;
+use std::string::{String, ToString};
+use std::vec::Vec;
+use std::{format, vec};
 
 #[test]
 fn methods_empty() {
     let mut arr: [i32; 0] = [];
 
     assert_eq!(arr.len(), 0);
-    assert_eq!(arr.is_empty(), true);
+    assert!(arr.is_empty());
     assert_eq!(arr.first(), None);
     assert_eq!(arr.first_mut(), None);
     assert_eq!(arr.last(), None);
     assert_eq!(arr.last_mut(), None);
+    assert_eq!(arr.head_tail(), None);
     assert_eq!(arr.get(0), None);
     assert_eq!(arr.get_mut(0), None);
+    assert_eq!(arr.get_or(0, &-1), &-1);
     assert_eq!(arr.map_(|a| a * 2), []);
     assert_eq!(arr.foldl(0, |a, n| a + n), 0);
     assert_eq!(arr.foldr(0, |a, n| a + n), 0);
+    assert_eq!(arr.reduce(i32::max), None);
 }
 
 fn test_arr<T: Array<V>, V: PartialEq + Debug>(mut arr: T, len: usize, mut first: V, mut last: V, mut second: V) {
     assert_eq!(arr.len(), len);
-    assert_eq!(arr.is_empty(), false);
+    assert!(!arr.is_empty());
     assert_eq!(arr.first(), Some(&first));
     assert_eq!(arr.first_mut(), Some(&mut first));
     assert_eq!(arr.last(), Some(&last));
@@ -30,14 +38,22 @@ fn test_arr<T: Array<V>, V: PartialEq + Debug>(mut arr: T, len: usize, mut first
     assert_eq!(arr.get(42), None);
     assert_eq!(arr.get_mut(1), Some(&mut second));
     assert_eq!(arr.get_mut(42), None);
+    assert_eq!(arr.get_or(1, &first), &second);
+    assert_eq!(arr.get_or(42, &first), &first);
+    assert_eq!(arr.get_or_else(42, || &last), &last);
 }
 
 #[test]
 fn methods() {
     let arr = [1, 2, 3, 4];
     test_arr(arr, 4, 1, 4, 2);
+    assert_eq!(arr.head_tail(), Some((&1, &[2, 3, 4][..])));
+    assert_eq!([5].head_tail(), Some((&5, &[][..])));
     assert_eq!(arr.map_(|a| a * 2), [2, 4, 6, 8]);
     assert_eq!(arr.foldl(0, |a, n| a + n), 10);
+    assert_eq!(arr.reduce(|a, n| a * n), Some(24));
+    assert_eq!([3, 1, 4, 1, 5].reduce(i32::max), Some(5));
+    assert_eq!([7].reduce(|_, _| unreachable!()), Some(7));
 
     let arr = ['c', 'd', 'e', 'f', 'g', 'a', 'b'];
     test_arr(arr, 7, 'c', 'b', 'd');
@@ -74,12 +90,26 @@ fn avg<T: Array<f32>>(arr: T) -> f32 {
     arr.foldl(0.0, |a, n| a + n) / n
 }
 
+fn buffer_for<T: ArrayN<i32, N> + ConstLen, const N: usize>(arr: T) -> [i32; N] {
+    let mut buf = [0; N];
+    buf[..const { T::LEN }].copy_from_slice(arr.downcast_ref());
+    buf
+}
+
 fn join_str<T: ArrayN<i32, N>, const N: usize>(arr: T) -> String {
     arr.downcast()
         .map(|n| n.to_string())
         .foldl(String::new(), |a, s| a + &s)
 }
 
+struct Counted<'a>(&'a Cell<usize>, i32);
+
+impl Drop for Counted<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
 #[test]
 fn generics() {
     assert_eq!(sum([], 1), []);
@@ -92,6 +122,85 @@ fn generics() {
     assert_eq!(avg([1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0, 256.0, 512.0]), 102.3);
 
     assert_eq!(join_str([1, 2, 42, 69]), "124269");
+
+    const LEN: usize = <[u8; 5]>::LEN;
+    assert_eq!([0u16; LEN].len(), 5);
+    assert_eq!(<[u8; 0]>::LEN, 0);
+    assert_eq!(buffer_for([3, 2, 1]), [3, 2, 1]);
+}
+
+#[test]
+fn get_range() {
+    let mut buf = [4, 1, 2, 3, 0, 0];
+    let len = buf[0];
+    assert_eq!(buf.get_range(1..len), Some(&[1, 2, 3][..]));
+    assert_eq!(buf.get_range(1..=len), Some(&[1, 2, 3, 0][..]));
+    assert_eq!(buf.get_range(..), Some(&buf[..]));
+    assert_eq!(buf.get_range(4..), Some(&[0, 0][..]));
+    assert_eq!(buf.get_range(6..), Some(&[][..]));
+    assert_eq!(buf.get_range(3..7), None);
+    assert_eq!(buf.get_range(7..), None);
+    assert_eq!(buf.get_range(..=6), None);
+
+    buf.get_range_mut(4..).unwrap().copy_from_slice(&[8, 9]);
+    assert_eq!(buf, [4, 1, 2, 3, 8, 9]);
+    assert!(buf.get_range_mut(5..9).is_none());
+}
+
+#[test]
+fn split_array() {
+    let mut packet = [2u8, 0, 1, 0xde, 0xad];
+    let (header, body) = packet.try_split_array_ref::<3>().unwrap();
+    assert_eq!(header, &[2, 0, 1]);
+    assert_eq!(body, [0xde, 0xad]);
+
+    let (header, body) = packet.try_split_array_mut::<3>().unwrap();
+    header[1] = body.len() as u8;
+    body[0] = 0xbe;
+    assert_eq!(packet, [2, 2, 1, 0xbe, 0xad]);
+
+    assert!(packet
+        .try_split_array_ref::<5>()
+        .is_some_and(|(_, body)| body.is_empty()));
+    assert!(packet.try_split_array_ref::<6>().is_none());
+    assert!(packet.try_split_array_mut::<6>().is_none());
+}
+
+#[test]
+fn as_chunks() {
+    let mut buf = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+    let (chunks, rem) = buf.as_chunks::<4>();
+    assert_eq!(chunks, [[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]);
+    assert_eq!(rem, [9.0, 10.0]);
+
+    let (chunks, rem) = buf.as_chunks_mut::<4>();
+    for lanes in chunks {
+        *lanes = lanes.map(|x| x * 2.0);
+    }
+    rem.fill(0.0);
+    assert_eq!(buf, [2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0, 0.0, 0.0]);
+
+    let (chunks, rem) = [1, 2].as_chunks::<3>();
+    assert!(chunks.is_empty());
+    assert_eq!(rem, [1, 2]);
+}
+
+#[test]
+fn swizzle() {
+    let rgb = [0.1, 0.2, 0.3];
+    assert_eq!(rgb.swizzle([2, 1, 0]), [0.3, 0.2, 0.1]);
+    assert_eq!(rgb.swizzle([0, 0, 1, 1]), [0.1, 0.1, 0.2, 0.2]);
+    assert_eq!(rgb.swizzle([]), [0.0; 0]);
+
+    let names = ["foo".to_string(), "bar".to_string()];
+    assert_eq!(names.try_swizzle([1, 0]), Some(["bar".to_string(), "foo".to_string()]));
+    assert_eq!(names.try_swizzle([1, 2]), None);
+}
+
+#[test]
+#[should_panic(expected = "swizzle index 3 is out of bounds for array of length 3")]
+fn swizzle_panic() {
+    [1, 2, 3].swizzle([0, 3]);
 }
 
 #[test]
@@ -110,6 +219,15 @@ fn slice() {
 
 #[test]
 fn constructors() {
+    assert_eq!(<[i32; 3]>::from_default(), [0, 0, 0]);
+    let arr: [Point; 2] = Array::from_default();
+    assert_eq!(arr, [Point(1, 1), Point(1, 1)]);
+
+    let arr: [String; 3] = Array::repeat("x".to_string());
+    assert_eq!(arr, ["x", "x", "x"]);
+    let arr: [String; 0] = Array::repeat("x".to_string());
+    assert_eq!(arr, [""; 0]);
+
     let arr: [usize; 5] = Array::from_iter(1..).unwrap();
     assert_eq!(arr, [1, 2, 3, 4, 5]);
 
@@ -119,6 +237,379 @@ fn constructors() {
 
     let arr: Option<[i32; 3]> = Array::from_iter(std::iter::once(1));
     assert_eq!(arr, None);
+
+    let v = [1, 2, 3];
+    let arr: Option<[i32; 3]> = Array::from_slice(&v[..]);
+    assert_eq!(arr, Some([1, 2, 3]));
+    let arr: Option<[i32; 2]> = Array::from_slice(&v[..]);
+    assert_eq!(arr, None);
+    let arr: Option<[String; 1]> = Array::from_slice(&["x".to_string()]);
+    assert_eq!(arr, Some(["x".to_string()]));
+
+    let arr: [usize; 5] = Array::from_iter_padded(1..3, |i| i * 10);
+    assert_eq!(arr, [1, 2, 20, 30, 40]);
+
+    let arr: [usize; 3] = Array::from_iter_padded(1.., |_| 0);
+    assert_eq!(arr, [1, 2, 3]);
+
+    let arr: [usize; 2] = Array::from_iter_padded(std::iter::empty(), |i| i);
+    assert_eq!(arr, [0, 1]);
+
+    #[derive(Debug, PartialEq)]
+    struct Point(i32, i32);
+
+    impl Default for Point {
+        fn default() -> Self {
+            Point(1, 1)
+        }
+    }
+}
+
+#[test]
+fn swap() {
+    let mut arr = [1, 2, 3];
+    arr.swap(0, 2);
+    assert_eq!(arr, [3, 2, 1]);
+    arr.swap(1, 1);
+    assert_eq!(arr, [3, 2, 1]);
+}
+
+#[test]
+#[should_panic]
+fn swap_out_of_bounds() {
+    [1, 2, 3].swap(0, 3);
+}
+
+#[test]
+fn fill() {
+    let mut arr = [0; 4];
+    arr.fill(7);
+    assert_eq!(arr, [7, 7, 7, 7]);
+
+    let mut n = 0;
+    arr.fill_with(|| {
+        n += 1;
+        n
+    });
+    assert_eq!(arr, [1, 2, 3, 4]);
+
+    let mut names = [String::new(), String::new()];
+    names.fill("x".to_string());
+    assert_eq!(names, ["x", "x"]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn sort() {
+    let mut arr = [5, 2, 9, 1, 5, 6];
+    arr.sort();
+    assert_eq!(arr, [1, 2, 5, 5, 6, 9]);
+
+    let mut arr = ["pear", "apple", "fig"];
+    arr.sort_unstable();
+    assert_eq!(arr, ["apple", "fig", "pear"]);
+
+    let mut arr = [0; 0];
+    arr.sort();
+    arr.sort_unstable();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn sort_by() {
+    let mut arr = [("c", 2), ("a", 3), ("b", 1), ("d", 2)];
+    arr.sort_by_key(|t| t.1);
+    assert_eq!(arr, [("b", 1), ("c", 2), ("d", 2), ("a", 3)]);
+    arr.sort_by(|a, b| b.1.cmp(&a.1));
+    assert_eq!(arr, [("a", 3), ("c", 2), ("d", 2), ("b", 1)]);
+}
+
+#[test]
+fn binary_search() {
+    let arr = [1, 3, 5, 7, 9];
+    assert_eq!(arr.binary_search(&7), Ok(3));
+    assert_eq!(arr.binary_search(&4), Err(2));
+    assert_eq!(arr.binary_search(&0), Err(0));
+    assert_eq!(arr.binary_search(&10), Err(5));
+
+    let arr = [(1, 'a'), (4, 'b'), (9, 'c')];
+    assert_eq!(arr.binary_search_by(|t| t.0.cmp(&4)), Ok(1));
+    assert_eq!(arr.binary_search_by(|t| t.0.cmp(&5)), Err(2));
+    assert_eq!([0; 0].binary_search(&1), Err(0));
+}
+
+#[test]
+fn position() {
+    assert_eq!([10, 20, 30].position(|&x| x == 20), Some(1));
+    assert_eq!([10, 20, 30, 20].position(|&x| x >= 20), Some(1));
+    assert_eq!([10, 20, 30].position(|&x| x == 40), None);
+    assert_eq!([0; 0].position(|_| true), None);
+}
+
+#[test]
+fn find() {
+    let opts = [("width", 80), ("tabs", 4), ("depth", 3), ("tabs", 8)];
+    assert_eq!(opts.find(|o| o.0 == "tabs"), Some(&("tabs", 4)));
+    assert_eq!(opts.find(|o| o.0 == "color"), None);
+
+    let words = ["one", "2", "three", "4"];
+    assert_eq!(words.find_map(|w| w.parse::<i32>().ok()), Some(2));
+    assert_eq!(words.find_map(|w| w.strip_prefix('x')), None);
+
+    let mut calls = 0;
+    [1, 2, 3, 4].find_map(|&n| {
+        calls += 1;
+        (n == 2).then_some(n)
+    });
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn all_any() {
+    let ports = [80, 443, 8080];
+    assert!(ports.all(|&p| p > 0));
+    assert!(!ports.all(|&p| p < 1024));
+    assert!(ports.any(|&p| p == 443));
+    assert!(!ports.any(|&p| p == 22));
+
+    assert!([0; 0].all(|_| false));
+    assert!(![0; 0].any(|_| true));
+}
+
+#[test]
+fn contains() {
+    assert!([1, 2, 3].contains(&2));
+    assert!(![1, 2, 3].contains(&9));
+    assert!(["a", "b"].contains(&"b"));
+    assert!(![0.0; 0].contains(&0.0));
+}
+
+#[test]
+fn is_palindrome() {
+    assert!([1, 2, 3, 2, 1].is_palindrome());
+    assert!(['a', 'b', 'b', 'a'].is_palindrome());
+    assert!(![1, 2, 3].is_palindrome());
+    assert!(![1, 2].is_palindrome());
+    assert!([7].is_palindrome());
+    assert!([0; 0].is_palindrome());
+}
+
+#[test]
+fn count() {
+    assert_eq!([1, 2, 3, 4].count(|&x| x % 2 == 0), 2);
+    assert_eq!([1, 2, 3, 4].count(|&x| x > 4), 0);
+    assert_eq!([0; 0].count(|_| true), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn positions() {
+    let arr = ['x', '|', 'y', 'z', '|', '|'];
+    assert_eq!(arr.positions(|&c| c == '|'), [1, 4, 5]);
+    assert_eq!(arr.positions(|&c| c == '#'), []);
+    assert_eq!([1, 2, 3].positions(|_| true), [0, 1, 2]);
+}
+
+#[test]
+fn count_leading() {
+    let buf = [0u8, 0, 0, 7, 9, 0];
+    assert_eq!(buf.count_leading(|&b| b == 0), 3);
+    assert_eq!(buf.count_trailing(|&b| b == 0), 1);
+    assert_eq!(buf.count_leading(|&b| b != 0), 0);
+    assert_eq!(buf.count_trailing(|&b| b < 10), 6);
+    assert_eq!([0u8; 0].count_leading(|_| true), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn local_extrema() {
+    let spectrum = [9.0, 1.0, 5.0, 2.0, 2.0, 7.0, 3.0, 8.0];
+    assert_eq!(spectrum.local_maxima(), [2, 5]);
+    assert_eq!(spectrum.local_minima(), [1, 6]);
+
+    assert_eq!([1, 2, 2, 1].local_maxima(), []);
+    assert_eq!([3, 1].local_minima(), []);
+    assert_eq!([0; 0].local_maxima(), []);
+
+    assert_eq!([5, 1, 3].local_maxima(), []);
+    assert_eq!([5, 1, 3].local_maxima_with(false), []);
+    assert_eq!([5, 1, 3].local_maxima_with(true), [0, 2]);
+    assert_eq!([5, 1, 3].local_minima_with(false), [1]);
+    assert_eq!([5, 1, 3].local_minima_with(true), [1]);
+    assert_eq!([1, 4, 9].local_maxima_with(true), [2]);
+    assert_eq!([1, 4, 9].local_minima_with(true), [0]);
+    assert_eq!([2, 2, 1].local_maxima_with(true), []);
+    assert_eq!(spectrum.local_maxima_with(true), [0, 2, 5, 7]);
+    assert_eq!(spectrum.local_minima_with(true), [1, 6]);
+    assert_eq!([7].local_maxima_with(true), []);
+    assert_eq!([0; 0].local_minima_with(true), []);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn find_all_subarrays() {
+    let frame = [0xAAu8, 0xAA, 0xAA, 0x55, 0xAA, 0xAA];
+    assert_eq!(frame.find_all_subarrays(&[0xAA, 0xAA]), [0, 1, 4]);
+    assert_eq!(frame.find_all_subarrays(&[0x55]), [3]);
+    assert_eq!(frame.find_all_subarrays(&[0x55, 0x55]), []);
+    assert_eq!(frame.find_all_subarrays(&frame), [0]);
+    assert_eq!(frame.find_all_subarrays(&[0; 7]), []);
+    assert_eq!([1, 2].find_all_subarrays(&[]), [0, 1, 2]);
+}
+
+#[test]
+fn min_max() {
+    let arr = [3, 1, 4, 1, 5, 9, 2, 6];
+    assert_eq!(arr.min_(), Some(&1));
+    assert_eq!(arr.max_(), Some(&9));
+    assert_eq!([0; 0].min_(), None);
+    assert_eq!([0; 0].max_(), None);
+
+    let arr = [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')].map(|(k, c)| Key(k, c));
+    assert_eq!(arr.min_(), Some(&Key(0, 'b')));
+    assert_eq!(arr.max_(), Some(&Key(1, 'c')));
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Key(i32, char);
+
+    impl PartialOrd for Key {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Key {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+}
+
+#[test]
+fn minmax_by_key() {
+    let items = [("box", 4), ("pen", 1), ("anvil", 90), ("cap", 1), ("rock", 90)];
+    assert_eq!(items.minmax_by_key(|t| t.1), Some((&("pen", 1), &("rock", 90))));
+    assert_eq!(items.minmax_by_key(|t| t.0.len()), Some((&("box", 4), &("anvil", 90))));
+
+    assert_eq!([2, 1].minmax_by_key(|&n| n), Some((&1, &2)));
+    assert_eq!([42].minmax_by_key(|&n| n), Some((&42, &42)));
+    assert_eq!([0; 0].minmax_by_key(|&n| n), None);
+
+    let mut calls = 0;
+    [5, 3, 8, 1].minmax_by_key(|&n| {
+        calls += 1;
+        n
+    });
+    assert_eq!(calls, 4);
+}
+
+#[test]
+fn min_max_by_key() {
+    let arr = [(1, "a"), (3, "b"), (2, "c")];
+    assert_eq!(arr.max_by_key(|t| t.0), Some(&(3, "b")));
+    assert_eq!(arr.min_by_key(|t| t.0), Some(&(1, "a")));
+
+    let arr = [(0, 'a'), (1, 'b'), (0, 'c'), (1, 'd')];
+    assert_eq!(arr.min_by_key(|t| t.0), Some(&(0, 'a')));
+    assert_eq!(arr.max_by_key(|t| t.0), Some(&(1, 'd')));
+
+    assert_eq!([0; 0].min_by_key(|&n| n), None);
+    assert_eq!([0; 0].max_by_key(|&n| n), None);
+}
+
+#[test]
+fn cycle_n() {
+    let arr = [1, 2, 3];
+    assert!(arr.cycle_n(2).eq(&[1, 2, 3, 1, 2, 3]));
+    assert_eq!(arr.cycle_n(0).next(), None);
+
+    let pattern: Option<[i32; 6]> = Array::from_iter(arr.cycle_n(2).copied());
+    assert_eq!(pattern, Some([1, 2, 3, 1, 2, 3]));
+
+    let arr: [i32; 0] = [];
+    assert_eq!(arr.cycle_n(usize::MAX).next(), None);
+}
+
+#[test]
+fn rotate_to() {
+    let mut log = [(7, "g"), (8, "h"), (3, "c"), (4, "d"), (5, "e")];
+    assert!(log.rotate_to(|&(t, _)| t == 3));
+    assert_eq!(log.map(|(_, s)| s), ["c", "d", "e", "g", "h"]);
+
+    assert!(!log.rotate_to(|&(t, _)| t > 10));
+    assert_eq!(log.map(|(t, _)| t), [3, 4, 5, 7, 8]);
+
+    let mut arr: [i32; 0] = [];
+    assert!(!arr.rotate_to(|_| true));
+}
+
+#[test]
+fn adjacent_apply() {
+    let mut arr = [0, 10, 0, 10];
+    arr.adjacent_apply_mut(|a, b| {
+        let avg = (*a + *b) / 2;
+        *a = avg;
+        *b = avg;
+    });
+    assert_eq!(arr, [5, 2, 6, 6]);
+
+    let mut arr = [1, 2, 3, 4, 5];
+    arr.adjacent_apply_mut(std::mem::swap);
+    assert_eq!(arr, [2, 3, 4, 5, 1]);
+
+    let mut arr: [i32; 1] = [42];
+    arr.adjacent_apply_mut(|_, _| unreachable!());
+    assert_eq!(arr, [42]);
+}
+
+#[test]
+fn try_from_fn() {
+    let arr: Result<[usize; 4], ()> = Array::try_from_fn(|i| Ok(i * i));
+    assert_eq!(arr, Ok([0, 1, 4, 9]));
+
+    let arr: Result<[i32; 3], _> = Array::try_from_fn(|i| ["7", "x", "9"][i].parse::<i32>());
+    assert!(arr.is_err());
+
+    let drops = Cell::new(0);
+    let arr: Result<[Counted; 5], usize> = Array::try_from_fn(|i| if i < 3 { Ok(Counted(&drops, 0)) } else { Err(i) });
+    assert!(matches!(arr, Err(3)));
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn try_from_iter() {
+    let arr: Result<[i32; 3], usize> = Array::try_from_iter(1..);
+    assert_eq!(arr, Ok([1, 2, 3]));
+    let arr: Result<[i32; 3], usize> = Array::try_from_iter(1..3);
+    assert_eq!(arr, Err(2));
+    let arr: Result<[i32; 3], usize> = Array::try_from_iter(std::iter::empty());
+    assert_eq!(arr, Err(0));
+
+    let drops = Cell::new(0);
+    let arr: Result<[Counted; 3], usize> = Array::try_from_iter((0..2).map(|_| Counted(&drops, 0)));
+    assert!(matches!(arr, Err(2)));
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn map_idx() {
+    assert_eq!([5, 5, 5, 5].map_idx(|i, x| x * i as i32), [0, 5, 10, 15]);
+    let names = ["a".to_string(), "b".to_string()];
+    assert_eq!(names.map_idx(|i, s| format!("{}{}", s, i)), ["a0", "b1"]);
+    assert_eq!([0u8; 0].map_idx(|i, _| i), [0usize; 0]);
+}
+
+#[test]
+fn try_map() {
+    assert_eq!(["1", "2", "3"].try_map_(|s| s.parse::<i32>()), Ok([1, 2, 3]));
+    assert!(["1", "2", "x"].try_map_(|s| s.parse::<i32>()).is_err());
+
+    let drops = Cell::new(0);
+    let arr = [1, 2, -1, 4, 5].map(|n| Counted(&drops, n));
+    let res = arr.try_map_(|c| if c.1 > 0 { Ok(Counted(c.0, c.1 * 2)) } else { Err(c.1) });
+    assert!(matches!(res, Err(-1)));
+    // 5 inputs and the 2 outputs created before the error
+    assert_eq!(drops.get(), 7);
 }
 
 #[test]
@@ -132,8 +623,22 @@ fn sized() {
     let arr2 = [10, 20, 30];
     assert_eq!(arr.zip_with(arr2, |a, b| a + b), [11, 22, 33]);
 
+    let mut acc = [0; 3];
+    for _ in 0..3 {
+        acc = acc.zip_into(arr2, |a, b| a + b);
+    }
+    assert_eq!(acc, [30, 60, 90]);
+
     let arr3 = [100, 200, 300];
     assert_eq!(arr.zip3_with(arr2, arr3, |a, b, c| a + b + c), [111, 222, 333]);
+    assert_eq!(arr.zip3_fold(arr2, arr3, 0, |acc, a, b, c| acc + a * b * c), 36000);
+
+    let bary = [0.5, 0.25, 0.25];
+    let (xs, ys) = ([0.0, 4.0, 0.0], [0.0, 0.0, 8.0]);
+    assert_eq!(
+        bary.zip3_fold(xs, ys, (0.0, 0.0), |(x, y), w, px, py| (x + w * px, y + w * py)),
+        (1.0, 2.0)
+    );
 
     let arr4 = [1000, 2000, 3000];
     assert_eq!(
@@ -146,6 +651,325 @@ fn sized() {
         arr.zip5_with(arr2, arr3, arr4, arr5, |a, b, c, d, e| a + b + c + d + e),
         [11111, 22222, 33333]
     );
+
+    let names = ["a".to_string(), "bb".to_string(), "ccc".to_string()];
+    assert_eq!(names.map_ref(String::len), [1, 2, 3]);
+    let a = [1.0, 0.5, 2.0];
+    let b = [2.0, 4.0, 0.5];
+    assert_eq!(a.zip_fold_ref(&b, 0.0, |acc, x, y| acc + x * y), 5.0);
+    assert_eq!(names.zip_fold_ref(&a, 0.0, |acc, s, w| acc + s.len() as f64 * w), 8.0);
+    assert_eq!(names.len(), 3);
+}
+
+#[test]
+fn zip_pairs() {
+    assert_eq!([1, 2].zip_pairs(['a', 'b']), [(1, 'a'), (2, 'b')]);
+    let names = ["x".to_string(), "y".to_string()];
+    assert_eq!(
+        names.zip_pairs([1.0, 2.0]),
+        [("x".to_string(), 1.0), ("y".to_string(), 2.0)]
+    );
+    assert_eq!([0; 0].zip_pairs(['a'; 0]), []);
+}
+
+#[test]
+fn masks() {
+    let a = [1, 5, 3, 8];
+    let b = [2, 4, 3, 9];
+    assert_eq!(a.mask_by(|&x| x > 2), [false, true, true, true]);
+    assert_eq!(a.gt_mask(&b), [false, true, false, false]);
+    assert_eq!(a.lt_mask(&b), [true, false, false, true]);
+    assert_eq!(a.eq_mask(&b), [false, false, true, false]);
+    assert_eq!(a.select(a.gt_mask(&b), b), [2, 5, 3, 9]);
+
+    assert_eq!(a.gt_mask(&b).count_true(), 1);
+    assert_eq!(a.mask_by(|&x| x > 2).count_false(), 1);
+    assert_eq!([true; 0].count_true(), 0);
+    assert_eq!([false; 3].count_false(), 3);
+}
+
+#[test]
+fn select() {
+    let a = [1, 2, 3, 4];
+    let b = [10, 20, 30, 40];
+    assert_eq!(a.select([true, false, true, false], b), [1, 20, 3, 40]);
+    assert_eq!(a.select([false; 4], b), b);
+
+    let a = ["a".to_string(), "b".to_string()];
+    let b = ["x".to_string(), "y".to_string()];
+    assert_eq!(a.select([false, true], b), ["x", "b"]);
+}
+
+#[test]
+fn arith_arr() {
+    assert_eq!([1, 2, 3].add_arr([10, 20, 30]), [11, 22, 33]);
+    assert_eq!([10, 20, 30].sub_arr([1, 2, 3]), [9, 18, 27]);
+    assert_eq!([1, 2, 3].mul_arr([4, 5, 6]), [4, 10, 18]);
+    assert_eq!([10.0, 9.0].div_arr([4.0, 3.0]), [2.5, 3.0]);
+    assert_eq!([0; 0].add_arr([]), []);
+}
+
+#[test]
+fn scalar_ops() {
+    assert_eq!([1, 2, 3].scale(2), [2, 4, 6]);
+    assert_eq!([1, 2, 3].offset(-1), [0, 1, 2]);
+    assert_eq!([0.5, 1.5].scale(2.0).offset(1.0), [2.0, 4.0]);
+}
+
+#[test]
+fn clamp_each() {
+    assert_eq!([-1, 5, 10].clamp_each(0, 8), [0, 5, 8]);
+    assert_eq!([0.5, -0.25, 1.5].clamp_each(0.0, 1.0), [0.5, 0.0, 1.0]);
+    assert_eq!([3, 4].clamp_each(4, 4), [4, 4]);
+}
+
+#[test]
+#[should_panic]
+fn clamp_each_panic() {
+    [1, 2].clamp_each(3, 0);
+}
+
+#[test]
+fn dot() {
+    assert_eq!([1, 2, 3].dot([4, 5, 6]), 32);
+    assert_eq!([1.0, 0.0].dot([0.0, 1.0]), 0.0);
+    assert_eq!([0u8; 0].dot([]), 0);
+}
+
+#[test]
+fn try_zip_with() {
+    let keys = ["a", "b", "c"];
+    assert_eq!(
+        keys.try_zip_with(["1", "2", "3"], |k, v| v.parse::<i32>().map(|v| (k, v))),
+        Ok([("a", 1), ("b", 2), ("c", 3)])
+    );
+    assert!(keys.try_zip_with(["1", "x", "3"], |_, v| v.parse::<i32>()).is_err());
+
+    assert_eq!([10, 20, 30].checked_div_arr([2, 5, 3]), Ok([5, 4, 10]));
+    assert_eq!(
+        [10, 20, 30].checked_div_arr([2, 0, 0]),
+        Err(DivError {
+            index: 1,
+            kind: DivErrorKind::DivByZero
+        })
+    );
+    assert_eq!(
+        [1u8].checked_div_arr([0]).unwrap_err().to_string(),
+        "division by zero at index 0"
+    );
+
+    assert_eq!(
+        [4, i32::MIN].checked_div_arr([2, -1]),
+        Err(DivError {
+            index: 1,
+            kind: DivErrorKind::Overflow
+        })
+    );
+    assert_eq!(
+        [i8::MIN].checked_div_arr([-1]).unwrap_err().to_string(),
+        "division overflow at index 0"
+    );
+    assert_eq!([u64::MAX, 7].checked_div_arr([1, 7]), Ok([u64::MAX, 1]));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn zip_map_while() {
+    let keys = ["a", "b", "", "d"];
+    let vals = [1, 2, 3, 4];
+    assert_eq!(
+        keys.zip_map_while(vals, |k, v| (!k.is_empty()).then(|| format!("{k}={v}"))),
+        ["a=1", "b=2"]
+    );
+    assert_eq!([1, 2].zip_map_while([3, 4], |a, b| Some(a * b)), [3, 8]);
+    assert_eq!([1, 2].zip_map_while([3, 4], |_, _| None::<i32>), []);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn cartesian_product() {
+    assert_eq!(
+        [1, 2].cartesian_product(&['a', 'b']),
+        [(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]
+    );
+    assert_eq!([1, 2, 3].cartesian_product(&["x"]), [(1, "x"), (2, "x"), (3, "x")]);
+    assert_eq!([1, 2].cartesian_product::<char>(&[]), []);
+    assert_eq!([0; 0].cartesian_product(&[1]), []);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn diff() {
+    let old = ["on", "off", "auto", "on"];
+    let new = ["on", "on", "auto", "off"];
+    assert_eq!(old.diff_indices(&new), [1, 3]);
+    assert_eq!(old.diff(&new), [(1, &"off", &"on"), (3, &"on", &"off")]);
+    assert_eq!(old.diff_indices(&old), []);
+}
+
+#[test]
+fn autocorrelation() {
+    let frame = [1.0f32, -1.0, 1.0, -1.0, 1.0, -1.0];
+    assert_eq!(frame.autocorrelation(0), 1.0);
+    assert!((frame.autocorrelation(1) + 5.0 / 6.0).abs() < 1e-12);
+    assert!((frame.autocorrelation(2) - 4.0 / 6.0).abs() < 1e-12);
+    assert_eq!(frame.autocorrelation(6), 0.0);
+
+    let ramp = [1u8, 2, 3, 4];
+    assert!((ramp.autocorrelation(1) - 0.25).abs() < 1e-12);
+    assert!([5; 4].autocorrelation(1).is_nan());
+}
+
+#[test]
+fn resample_linear() {
+    let curve = [0.0f32, 10.0, 20.0];
+    assert_eq!(curve.resample_linear::<5>(), [0.0, 5.0, 10.0, 15.0, 20.0]);
+    assert_eq!(curve.resample_linear::<2>(), [0.0, 20.0]);
+    assert_eq!(curve.resample_linear::<1>(), [0.0]);
+
+    let env = [1u8, 3, 2, 7];
+    let up = env.resample_linear::<7>();
+    assert_eq!(up, [1.0, 2.0, 3.0, 2.5, 2.0, 4.5, 7.0]);
+    assert_eq!(up.resample_linear::<4>(), [1.0, 3.0, 2.0, 7.0]);
+
+    let spiky = [0u16, 10, 20, 1000];
+    assert_eq!(spiky.resample_linear::<48>()[47], 1000.0);
+    let ramp: [u32; 100] = std::array::from_fn(|i| (i * i) as u32);
+    let down = ramp.resample_linear::<50>();
+    assert_eq!((down[0], down[49]), (0.0, 9801.0));
+
+    assert_eq!([5; 1].resample_linear::<3>(), [5.0; 3]);
+    assert!([0u8; 0].resample_linear::<2>().iter().all(|x| x.is_nan()));
+}
+
+#[test]
+fn windows_iter() {
+    let arr = [1, 2, 3, 4, 5];
+    let mut it = arr.windows_iter::<3>();
+    assert_eq!(it.next(), Some(&[1, 2, 3]));
+    assert_eq!(it.next(), Some(&[2, 3, 4]));
+    assert_eq!(it.next(), Some(&[3, 4, 5]));
+    assert_eq!(it.next(), None);
+
+    assert_eq!(arr.windows_iter::<1>().count(), 5);
+    assert_eq!(arr.windows_iter::<5>().count(), 1);
+    assert_eq!(arr.windows_iter::<6>().count(), 0);
+
+    let sums: Option<[i32; 4]> = Array::from_iter(arr.windows_iter().map(|[a, b]| a + b));
+    assert_eq!(sums, Some([3, 5, 7, 9]));
+}
+
+#[test]
+fn windows_array() {
+    let arr = [1, 2, 3, 4, 5];
+    assert_eq!(arr.windows_array::<3, 3>(), [[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+    assert_eq!(arr.windows_array::<5, 1>(), [arr]);
+    assert_eq!(arr.windows_array::<6, 0>(), [[0; 6]; 0]);
+    let w: [[i32; 2]; 4] = arr.windows_array();
+    assert_eq!(w, [[1, 2], [2, 3], [3, 4], [4, 5]]);
+    assert_eq!(arr.windows(2).count(), 4);
+
+    let names = ["a".to_string(), "b".to_string(), "c".to_string()];
+    assert_eq!(names.windows_array::<2, 2>(), [["a", "b"], ["b", "c"]]);
+}
+
+#[test]
+#[should_panic]
+fn windows_array_wrong_size() {
+    [1, 2, 3].windows_array::<2, 3>();
+}
+
+#[test]
+fn reverse() {
+    let mut arr = [1, 2, 3, 4];
+    arr.reverse();
+    assert_eq!(arr, [4, 3, 2, 1]);
+    assert_eq!([1, 2, 3].reversed(), [3, 2, 1]);
+    assert_eq!(["a".to_string(), "b".to_string()].reversed(), ["b", "a"]);
+    assert_eq!([0; 0].reversed(), [0; 0]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn sorted() {
+    assert_eq!([3, 1, 2].sorted(), [1, 2, 3]);
+    assert_eq!([3, 1, 2].sorted().reversed(), [3, 2, 1]);
+    let names = ["bob".to_string(), "al".to_string(), "cy".to_string()];
+    assert_eq!(names.sorted(), ["al", "bob", "cy"]);
+}
+
+#[test]
+fn rotate_const() {
+    let arr = [1, 2, 3, 4, 5];
+    assert_eq!(arr.rotated_left::<2>(), [3, 4, 5, 1, 2]);
+    assert_eq!(arr.rotated_right::<2>(), [4, 5, 1, 2, 3]);
+    assert_eq!(arr.rotated_left::<0>(), arr);
+    assert_eq!(arr.rotated_left::<7>(), [3, 4, 5, 1, 2]);
+    assert_eq!(arr.rotated_right::<10>(), arr);
+    assert_eq!([0; 0].rotated_left::<3>(), [0; 0]);
+    assert_eq!([0; 0].rotated_right::<3>(), [0; 0]);
+
+    let mut buf = arr;
+    buf.rotate_left(1);
+    assert_eq!(buf, [2, 3, 4, 5, 1]);
+}
+
+#[test]
+fn rotate_to_min() {
+    assert_eq!([3, 1, 2].rotate_to_min(), [1, 2, 3]);
+    assert_eq!([2, 3, 1].rotate_to_min(), [1, 2, 3]);
+    assert_eq!([1, 2, 1, 1].rotate_to_min(), [1, 1, 1, 2]);
+    assert_eq!([2, 1, 3, 1, 2].rotate_to_min(), [1, 2, 2, 1, 3]);
+    assert_eq!([5, 5].rotate_to_min(), [5, 5]);
+    assert_eq!([0; 0].rotate_to_min(), []);
+}
+
+#[test]
+fn checked_index() {
+    let mut arr = [10, 20, 30];
+    assert_eq!(arr.at(1), &20);
+    *arr.at_mut(2) += 3;
+    assert_eq!(arr, [10, 20, 33]);
+
+    assert_eq!(arr.try_at(0), Ok(&10));
+    assert_eq!(arr.try_at(3), Err(OutOfBounds { index: 3, len: 3 }));
+    assert_eq!(arr.try_at_mut(1), Ok(&mut 20));
+    assert_eq!(arr.try_at_mut(7), Err(OutOfBounds { index: 7, len: 3 }));
+    assert_eq!(
+        arr.try_at(5).unwrap_err().to_string(),
+        "index 5 is out of bounds for array of length 3"
+    );
+}
+
+#[test]
+#[should_panic]
+fn checked_index_panic() {
+    [1, 2, 3].at(3);
+}
+
+#[test]
+fn uninit() {
+    let mut arr = <[String; 4]>::uninit();
+    for (i, item) in arr.iter_mut().enumerate() {
+        item.write(i.to_string());
+    }
+    let arr = unsafe { <[String; 4]>::assume_init(arr) };
+    assert_eq!(arr, ["0", "1", "2", "3"]);
+}
+
+#[test]
+fn sum_product() {
+    assert_eq!([1, 2, 3, 4].sum::<i32>(), 10);
+    assert_eq!([0i32; 0].sum::<i32>(), 0);
+    assert_eq!([1.5, 2.25].sum::<f64>(), 3.75);
+    assert_eq!([&1u64, &2, &3].sum::<u64>(), 6);
+
+    assert_eq!([1, 2, 3, 4, 5].product::<i32>(), 120);
+    assert_eq!([2u64, 3, 7].product::<u64>(), 42);
+    assert_eq!([0.5, 4.0, 1.5].product::<f64>(), 3.0);
+    assert_eq!([0i32; 0].product::<i32>(), 1);
+    assert_eq!([0u64; 0].product::<u64>(), 1);
+    assert_eq!([0f64; 0].product::<f64>(), 1.0);
 }
 
 #[test]
@@ -168,6 +992,7 @@ fn non_copy() {
     struct Test(i32);
 
     let arr = [Test(1), Test(2), Test(3)];
+    assert_eq!(arr.map_ref(|a| a.0 * 3), [3, 6, 9]);
     assert_eq!(arr.clone().map_(|a| Test(a.0 * 2)), [Test(2), Test(4), Test(6)]);
     assert_eq!(arr.clone().map(|a| a.0 * 2), [2, 4, 6]);
     assert_eq!(arr.clone().foldl(0, |a, n| a + n.0), 6);
@@ -192,6 +1017,8 @@ fn non_copy() {
         [1111, 2222, 3333]
     );
 
+    assert_eq!(arr.zip_fold_ref(&arr2, 0, |acc, a, b| acc + a.0 * b.0), 140);
+
     let arr5 = [Test(10000), Test(20000), Test(30000)];
     assert_eq!(
         arr.zip5_with(arr2, arr3, arr4, arr5, |a, b, c, d, e| a.0 + b.0 + c.0 + d.0 + e.0),
@@ -199,6 +1026,208 @@ fn non_copy() {
     );
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn options() {
+    let arr = [Some(1), None, Some(3), None, None];
+    assert_eq!(arr.flatten_options(), [1, 3]);
+    assert_eq!(arr.partition_options(), (vec![1, 3], 3));
+
+    let arr = [Some("foo".to_string()), Some("bar".to_string())];
+    assert_eq!(arr.partition_options(), (vec!["foo".to_string(), "bar".to_string()], 0));
+
+    let arr: [Option<i32>; 2] = [None, None];
+    assert_eq!(arr.flatten_options(), []);
+}
+
+#[test]
+fn transpose_options() {
+    assert_eq!([Some(1), Some(2), Some(3)].transpose_options(), Some([1, 2, 3]));
+    assert_eq!([Some(1), None, Some(3)].transpose_options(), None);
+    assert_eq!([None::<i32>; 0].transpose_options(), Some([]));
+
+    let fields = ["1", "2", "3"].map(str::parse::<i32>);
+    assert_eq!(fields.transpose_results(), Ok([1, 2, 3]));
+
+    let fields = ["1", "x", "y"].map(|s| s.parse::<i32>().map_err(|_| s));
+    assert_eq!(fields.transpose_results(), Err("x"));
+}
+
+#[test]
+fn weighted_fold() {
+    fn luhn<const N: usize>(digits: &[u32; N]) -> bool {
+        let sum = digits.weighted_fold(0, |acc, i, &d| {
+            let d = if (N - i).is_multiple_of(2) { d * 2 } else { d };
+            acc + if d > 9 { d - 9 } else { d }
+        });
+        sum % 10 == 0
+    }
+
+    assert!(luhn(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1, 3]));
+    assert!(!luhn(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1, 4]));
+    assert_eq!([10, 20, 30].weighted_fold(0, |acc, i, n| acc + i * n), 80);
+}
+
+#[test]
+fn fold_both() {
+    let arr = ['a', 'b', 'c'];
+    let (l, r) = arr.fold_both(String::new(), |mut s, &c| {
+        s.push(c);
+        s
+    });
+    assert_eq!((l.as_str(), r.as_str()), ("abc", "cba"));
+
+    let arr = [1, 2, 3, 4];
+    assert_eq!(arr.fold_both(0, |acc, n| acc * 10 + n), (1234, 4321));
+    assert_eq!([0; 0].fold_both(7, |acc, n| acc + n), (7, 7));
+}
+
+#[test]
+fn scanl() {
+    assert_eq!([1, 2, 3, 4].scanl(0, |a, n| a + n), [0, 1, 3, 6, 10]);
+    assert_eq!(
+        ["a", "b", "c"].scanl(String::new(), |a, s| a.clone() + s),
+        ["", "a", "ab", "abc"]
+    );
+    assert_eq!([0u8; 0].scanl(42, |a, n| a + n), [42]);
+}
+
+#[test]
+#[should_panic(expected = "scanl output size must be the input size plus one")]
+fn scanl_panic() {
+    let _: [i32; 3] = [1, 2, 3].scanl(0, |a, n| a + n);
+}
+
+#[test]
+fn try_foldl() {
+    assert_eq!([1u8, 2, 3].try_foldl(0u8, |a, n| a.checked_add(n).ok_or(n)), Ok(6));
+    assert_eq!(
+        [100u8, 100, 100, 1].try_foldl(0u8, |a, n| a.checked_add(n).ok_or(n)),
+        Err(100)
+    );
+
+    let mut seen = Vec::new();
+    let res = ["1", "x", "3"].map(String::from).try_foldl(0, |acc, s| {
+        seen.push(s.clone());
+        s.parse::<i32>().map(|n| acc + n)
+    });
+    assert!(res.is_err());
+    assert_eq!(seen, ["1", "x"]);
+}
+
+#[test]
+fn try_fold_indexed() {
+    let record = ["12", "7", "x", "y"];
+    assert_eq!(
+        record.try_foldl_indexed(0, |acc, i, s| s.parse::<i32>().map(|n| acc + n).map_err(|_| i)),
+        Err(2)
+    );
+    assert_eq!(
+        [3, 4, 5].try_foldl_indexed(0, |acc, i, n| Ok::<_, ()>(acc + i * n)),
+        Ok(14)
+    );
+
+    let mut seen = Vec::new();
+    let res = ["a", "b", "c"].try_foldl_indexed((), |_, i, s| {
+        seen.push(s);
+        if i == 1 {
+            Err(i)
+        } else {
+            Ok(())
+        }
+    });
+    assert_eq!(res, Err(1));
+    assert_eq!(seen, ["a", "b"]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn rchunk_fold() {
+    let arr = [1, 2, 3, 4, 5, 6, 7];
+    assert_eq!(arr.rchunk_fold::<3, _, _>(0, |a, n| a + n), [1, 9, 18]);
+    assert_eq!(arr.rchunk_fold::<7, _, _>(0, |a, n| a + n), [28]);
+    assert_eq!(arr.rchunk_fold::<10, _, _>(0, |a, n| a + n), [28]);
+    assert_eq!(arr.rchunk_fold::<1, _, _>(0, |a, n| a + n), arr);
+    assert_eq!([0; 0].rchunk_fold::<2, _, _>(0, |a, n| a + n), []);
+
+    let arr = ['a', 'b', 'c', 'd', 'e'];
+    assert_eq!(
+        arr.rchunk_fold::<2, _, _>(String::new(), |mut a, c| {
+            a.push(c);
+            a
+        }),
+        ["a", "bc", "de"]
+    );
+}
+
+#[test]
+fn unzip3() {
+    let arr = [(1, 'a', true), (2, 'b', false), (3, 'c', true)];
+    assert_eq!(arr.unzip3(), ([1, 2, 3], ['a', 'b', 'c'], [true, false, true]));
+
+    let arr = [(1, "x".to_string(), vec![1])];
+    assert_eq!(arr.unzip3(), ([1], ["x".to_string()], [vec![1]]));
+
+    let arr: [(i32, char, bool); 0] = [];
+    assert_eq!(arr.unzip3(), ([], [], []));
+}
+
+#[test]
+fn cross() {
+    assert_eq!([1, 0, 0].cross([0, 1, 0]), [0, 0, 1]);
+    assert_eq!([0, 1, 0].cross([1, 0, 0]), [0, 0, -1]);
+    assert_eq!([1, 2, 3].cross([4, 5, 6]), [-3, 6, -3]);
+    assert_eq!([2.0, 0.0, 0.0].cross([4.0, 0.0, 0.0]), [0.0; 3]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn magnitude() {
+    assert_eq!([3.0, 4.0].magnitude(), 5.0);
+    assert_eq!([3.0f32, 4.0].normalized(), [0.6, 0.8]);
+    assert_eq!([0.0; 3].magnitude(), 0.0);
+    assert_eq!([0.0; 3].normalized(), [0.0; 3]);
+    assert_eq!([0.0f32; 0].magnitude(), 0.0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn float_rounding() {
+    let arr = [-1.5, -0.25, 0.5, 2.75];
+    assert_eq!(arr.abs(), [1.5, 0.25, 0.5, 2.75]);
+    assert_eq!(arr.floor(), [-2.0, -1.0, 0.0, 2.0]);
+    assert_eq!(arr.ceil(), [-1.0, -0.0, 1.0, 3.0]);
+    assert_eq!(arr.round(), [-2.0, -0.0, 1.0, 3.0]);
+    assert_eq!([-0.5f32].round(), [-1.0]);
+}
+
+#[test]
+fn transpose() {
+    assert_eq!([[1, 2, 3], [4, 5, 6]].transpose(), [[1, 4], [2, 5], [3, 6]]);
+    assert_eq!([[1, 2, 3]].transpose(), [[1], [2], [3]]);
+
+    let m = [["a", "b"], ["c", "d"]].map(|r| r.map(String::from));
+    assert_eq!(m.transpose(), [["a", "c"], ["b", "d"]]);
+
+    let m: [[i32; 3]; 0] = [];
+    assert_eq!(m.transpose(), [[0; 0]; 3]);
+}
+
+#[test]
+fn tuples() {
+    assert_eq!([1, 2].into_tuple(), (1, 2));
+    assert_eq!(['a', 'b', 'c'].into_tuple(), ('a', 'b', 'c'));
+    assert_eq!([1, 2, 3, 4, 5, 6, 7, 8].into_tuple(), (1, 2, 3, 4, 5, 6, 7, 8));
+    assert_eq!(
+        ["x".to_string(), "y".to_string()].into_tuple(),
+        ("x".to_string(), "y".to_string())
+    );
+
+    assert_eq!(<[i32; 3]>::from_tuple((4, 5, 6)), [4, 5, 6]);
+    let arr: [f32; 4] = IntoTuple::from_tuple((1.0, 2.0, 3.0, 4.0));
+    assert_eq!(arr, [1.0, 2.0, 3.0, 4.0]);
+}
+
 #[test]
 fn resize() {
     let arr = [1, 2, 3];
@@ -206,6 +1235,8 @@ fn resize() {
     assert_eq!(arr.resize(42), [1, 2, 3, 42, 42]);
     assert_eq!(arr.resize(42), [1, 2]);
     assert_eq!(arr.resize_with(|i| i + 1), [1, 2, 3, 4, 5, 6]);
+    assert_eq!(arr.resize_with_offset(|i| i), [1, 2, 3, 0, 1, 2]);
+    assert_eq!(arr.resize_with_offset(|_| unreachable!()), [1, 2]);
     assert_eq!(arr.resize::<0>(1), []);
     assert_eq!([].resize::<3>(0), [0; 3]);
 }
@@ -230,3 +1261,169 @@ fn split() {
     assert_eq!(arr.split::<0>(), ([], arr));
     assert_eq!(arr.split::<5>(), (arr, []));
 }
+
+#[cfg(feature = "nightly")]
+#[test]
+fn push() {
+    assert_eq!([1, 2, 3].push(4), [1, 2, 3, 4]);
+    assert_eq!([0; 0].push(1), [1]);
+    assert_eq!(["a".to_string()].push("b".to_string()), ["a", "b"]);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn pop() {
+    assert_eq!([1, 2, 3].pop(), ([1, 2], 3));
+    assert_eq!([1].pop(), ([], 1));
+    assert_eq!(
+        ["a".to_string(), "b".to_string()].pop(),
+        (["a".to_string()], "b".to_string())
+    );
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn insert() {
+    assert_eq!([1, 3].insert::<1>(2), [1, 2, 3]);
+    assert_eq!([1, 3].insert::<0>(0), [0, 1, 3]);
+    assert_eq!([1, 3].insert::<2>(4), [1, 3, 4]);
+    assert_eq!([0; 0].insert::<0>(5), [5]);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn remove() {
+    assert_eq!([1, 2, 3].remove::<1>(), ([1, 3], 2));
+    assert_eq!([1, 2, 3].remove::<0>(), ([2, 3], 1));
+    assert_eq!([1, 2, 3].remove::<2>(), ([1, 2], 3));
+    assert_eq!([7].remove::<0>(), ([], 7));
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn split_owned() {
+    assert_eq!([1, 2, 3].split_first_owned(), (1, [2, 3]));
+    assert_eq!([1, 2, 3].split_last_owned(), ([1, 2], 3));
+    assert_eq!([1].split_first_owned(), (1, []));
+    assert_eq!([1].split_last_owned(), ([], 1));
+
+    let arr = [1, 2, 3];
+    assert_eq!(arr.split_first(), Some((&1, &[2, 3][..])));
+    assert_eq!(arr.split_last(), Some((&3, &[1, 2][..])));
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn take_drop() {
+    let arr = [1, 2, 3, 4, 5];
+    assert_eq!(arr.take::<2>(), [1, 2]);
+    assert_eq!(arr.drop::<2>(), [3, 4, 5]);
+    assert_eq!(arr.take::<0>(), []);
+    assert_eq!(arr.drop::<0>(), arr);
+    assert_eq!(arr.take::<5>(), arr);
+    assert_eq!(arr.drop::<5>(), []);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn reshape() {
+    let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+    assert_eq!(arr.reshape::<3, 4>(), [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+    assert_eq!(arr.reshape::<4, 3>(), [[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]);
+    assert_eq!(arr.reshape::<1, 12>(), [arr]);
+    assert_eq!([0; 0].reshape::<0, 3>(), [[0; 3]; 0]);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn chunks_array() {
+    let arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    assert_eq!(arr.chunks_array::<3>(), [[0, 1, 2], [3, 4, 5], [6, 7, 8], [9, 10, 11]]);
+    assert_eq!(arr.chunks_array::<12>(), [arr]);
+    assert_eq!([0f32; 16].chunks_array::<4>(), [[0.0; 4]; 4]);
+    assert_eq!([0; 0].chunks_array::<2>(), [[0; 2]; 0]);
+    assert_eq!(arr.chunks(5).count(), 3);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn flatten() {
+    assert_eq!([[1, 2], [3, 4], [5, 6]].flatten(), [1, 2, 3, 4, 5, 6]);
+    let m = [["a", "b"], ["c", "d"]].map(|r| r.map(String::from));
+    assert_eq!(m.flatten(), ["a", "b", "c", "d"]);
+    assert_eq!([[0; 3]; 0].flatten(), [0; 0]);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn flatten_ref() {
+    let arr = [[1, 2, 3], [4, 5, 6]];
+    assert_eq!(arr.flatten_ref(), &[1, 2, 3, 4, 5, 6]);
+
+    let arr = [[0.5f32; 4]; 4];
+    let flat: &[f32; 16] = arr.flatten_ref();
+    assert_eq!(flat, &[0.5; 16]);
+
+    let arr: [[u8; 0]; 3] = [[]; 3];
+    assert_eq!(arr.flatten_ref(), &[]);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn interleave_n() {
+    let a = ["a0", "a1", "a2"];
+    let b = ["b0", "b1", "b2"];
+    let c = ["c0", "c1", "c2"];
+    assert_eq!(
+        super::interleave_n([a, b, c]),
+        ["a0", "b0", "c0", "a1", "b1", "c1", "a2", "b2", "c2"]
+    );
+    assert_eq!(super::interleave_n([[1, 2, 3, 4]]), [1, 2, 3, 4]);
+    assert_eq!(super::interleave_n([[1], [2]]), [1, 2]);
+    assert_eq!(super::interleave_n::<i32, 0, 3>([]), []);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn deinterleave() {
+    let stereo = ["l0", "r0", "l1", "r1", "l2", "r2"];
+    assert_eq!(
+        super::deinterleave::<_, 2, 3>(stereo),
+        [["l0", "l1", "l2"], ["r0", "r1", "r2"]]
+    );
+
+    let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let split: [[i32; 3]; 3] = super::deinterleave(arr);
+    assert_eq!(split, [[1, 4, 7], [2, 5, 8], [3, 6, 9]]);
+    assert_eq!(super::interleave_n(split), arr);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn aggregate_windows() {
+    let signal = [3.0f64, -4.0, 0.0, 4.0, -3.0];
+    let rms = signal.aggregate_windows(|w: &[f64; 2]| (w.foldl(0.0, |a, x| a + x * x) / 2.0).sqrt());
+    assert_eq!(rms.map(|x| (x * x * 2.0).round()), [25.0, 16.0, 16.0, 25.0]);
+
+    let arr = [1, 5, 2, 8, 3];
+    assert_eq!(
+        arr.aggregate_windows(|w: &[i32; 3]| *w.iter().max().unwrap()),
+        [5, 8, 8]
+    );
+    assert_eq!(arr.aggregate_windows(|w: &[i32; 5]| w.len()), [5]);
+    assert_eq!(arr.aggregate_windows(|w: &[i32; 0]| w.len()), [0; 6]);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn rolling_variance() {
+    let series = [2.0f32, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    let var = series.rolling_variance::<4>();
+    let expected = [0.75, 0.1875, 0.25, 1.1875, 2.75];
+    for (v, e) in var.into_iter().zip(expected) {
+        assert!((v - e).abs() < 1e-12, "{v} != {e}");
+    }
+    assert!((series.rolling_variance::<8>()[0] - 4.0).abs() < 1e-12);
+    assert_eq!(series.rolling_variance::<1>(), [0.0; 8]);
+}